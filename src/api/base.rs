@@ -1,6 +1,10 @@
+use super::TwitterApiBuilder;
 use crate::api_result::{ApiPayload, ApiResponse, ApiResponseExt, ApiResult};
 use crate::auth::Authorization;
 use crate::error::Result;
+use crate::rate_limit::{RateLimit, RateLimitMode, RateLimiter};
+use crate::requests::Paginator;
+use crate::retry::RetryPolicy;
 use crate::utils::JsonStream;
 use futures::prelude::*;
 use reqwest::header::AUTHORIZATION;
@@ -13,6 +17,8 @@ pub struct TwitterApi<A> {
     client: Client,
     base_url: Url,
     auth: Arc<A>,
+    rate_limiter: Option<RateLimiter>,
+    retry_policy: RetryPolicy,
 }
 
 impl<A> TwitterApi<A>
@@ -20,13 +26,49 @@ where
     A: Authorization,
 {
     pub fn new(auth: A) -> Self {
+        Self::builder(auth)
+            .build()
+            .expect("default client configuration is valid")
+    }
+
+    pub fn builder(auth: A) -> TwitterApiBuilder<A> {
+        TwitterApiBuilder::new(auth)
+    }
+
+    pub(super) fn from_parts(
+        client: Client,
+        base_url: Url,
+        auth: Arc<A>,
+        rate_limiter: Option<RateLimiter>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
         Self {
-            client: Client::builder().pool_max_idle_per_host(0).build().unwrap(),
-            base_url: Url::parse("https://api.twitter.com/2/").unwrap(),
-            auth: Arc::new(auth),
+            client,
+            base_url,
+            auth,
+            rate_limiter,
+            retry_policy,
         }
     }
 
+    /// Tracks the remaining quota of every endpoint called through this client (and its clones)
+    /// and holds back requests that would exceed it, according to `mode`.
+    pub fn with_rate_limit_mode(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limiter = Some(RateLimiter::new(mode));
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Pages through an arbitrary endpoint path (relative to the base URL) that takes a
+    /// `pagination_token`.
+    pub fn paginate<T: DeserializeOwned>(&self, path: impl AsRef<str>) -> Result<Paginator<A, T>> {
+        Ok(Paginator::new(self, self.url(path)?, "pagination_token"))
+    }
+
     pub fn auth(&self) -> &A {
         &self.auth
     }
@@ -39,24 +81,77 @@ where
         self.client.request(method, url)
     }
 
-    pub(crate) async fn send<T: DeserializeOwned>(
+    pub(crate) async fn send<T: DeserializeOwned, M: DeserializeOwned>(
         &self,
         req: reqwest::RequestBuilder,
-    ) -> ApiResult<T> {
+    ) -> ApiResult<T, M> {
+        self.send_retrying(req, false).await
+    }
+
+    /// Sends `req` according to the retry policy. Non-idempotent requests are only retried if
+    /// `retry_non_idempotent` is set.
+    pub(crate) async fn send_retrying<T: DeserializeOwned, M: DeserializeOwned>(
+        &self,
+        req: reqwest::RequestBuilder,
+        retry_non_idempotent: bool,
+    ) -> ApiResult<T, M> {
         let mut req = req.build()?;
+        let retryable = retry_non_idempotent || RetryPolicy::is_idempotent(req.method());
+        let mut attempt = 1;
+        loop {
+            let next = if retryable && attempt < self.retry_policy.attempts() {
+                req.try_clone()
+            } else {
+                None
+            };
+            match (next, self.send_once(req).await) {
+                (Some(next), Err(error)) if self.retry_policy.is_retryable(&error) => {
+                    let delay = match self.retry_policy.delay(attempt, &error) {
+                        Some(delay) => delay,
+                        None => return Err(error),
+                    };
+                    tracing::debug!(
+                        "Attempt {} failed with {}, retrying in {:?}",
+                        attempt,
+                        error,
+                        delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    req = next;
+                    attempt += 1;
+                }
+                (_, result) => return result,
+            }
+        }
+    }
+
+    async fn send_once<T: DeserializeOwned, M: DeserializeOwned>(
+        &self,
+        mut req: reqwest::Request,
+    ) -> ApiResult<T, M> {
+        // Sign only after any rate limit wait, so the timestamp, nonce or token is still fresh
+        let endpoint = RateLimiter::endpoint(req.method(), req.url());
+        if let Some(rate_limiter) = self.rate_limiter.as_ref() {
+            rate_limiter.acquire(&endpoint).await?;
+        }
         let authorization = self.auth.header(&req).await?;
         let _ = req.headers_mut().insert(AUTHORIZATION, authorization);
 
-        let response = self.client.execute(req).await?.api_error_for_status();
+        let response = self.client.execute(req).await?;
+        let rate_limit = RateLimit::from_headers(response.headers());
+        if let Some(rate_limiter) = self.rate_limiter.as_ref() {
+            rate_limiter.update(&endpoint, rate_limit);
+        }
+        let response = response.api_error_for_status().await?;
 
         // Get the response text for debugging
         let response_text = response.text().await?;
         tracing::debug!("Twitter API response: {}", response_text);
 
         // Parse the Twitter API v2 response format
-        let api_response: ApiPayload<T> = serde_json::from_str(&response_text)?;
+        let api_response: ApiPayload<T, M> = serde_json::from_str(&response_text)?;
 
-        Ok(ApiResponse::new(api_response))
+        Ok(ApiResponse::new(api_response).with_rate_limit(rate_limit))
     }
 
     #[allow(dead_code)]
@@ -80,6 +175,8 @@ impl<A> Clone for TwitterApi<A> {
             client: self.client.clone(),
             base_url: self.base_url.clone(),
             auth: self.auth.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            retry_policy: self.retry_policy.clone(),
         }
     }
 }
@@ -0,0 +1,77 @@
+use super::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::BookmarkStatus;
+use crate::error::Result;
+use crate::export::{export_tweets, ExportFormat};
+use crate::id::IntoNumericId;
+use crate::query::{TweetExpansion, TweetField, UserField};
+use crate::requests::{TimelineRequestBuilder, TweetList};
+use reqwest::Method;
+use serde_json::json;
+use std::io::Write;
+
+impl<A> TwitterApi<A>
+where
+    A: Authorization,
+{
+    pub async fn bookmark_tweet(
+        &self,
+        user_id: impl IntoNumericId,
+        tweet_id: impl IntoNumericId,
+    ) -> ApiResult<BookmarkStatus> {
+        self.send(
+            self.request(
+                Method::POST,
+                self.url(format!("users/{}/bookmarks", user_id.into_id()))?,
+            )
+            .json(&json!({ "tweet_id": tweet_id.into_id() })),
+        )
+        .await
+    }
+
+    pub async fn remove_bookmark(
+        &self,
+        user_id: impl IntoNumericId,
+        tweet_id: impl IntoNumericId,
+    ) -> ApiResult<BookmarkStatus> {
+        self.send(self.request(
+            Method::DELETE,
+            self.url(format!(
+                "users/{}/bookmarks/{}",
+                user_id.into_id(),
+                tweet_id.into_id()
+            ))?,
+        ))
+        .await
+    }
+
+    /// Bookmarks of the authenticated user, newest first.
+    pub fn get_user_bookmarks(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> TimelineRequestBuilder<A, TweetList> {
+        TimelineRequestBuilder::new(
+            self,
+            self.url(format!("users/{}/bookmarks", user_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    /// Writes every bookmark together with its author to `writer`, see [`export_tweets`].
+    pub async fn export_user_bookmarks(
+        &self,
+        user_id: impl IntoNumericId,
+        format: ExportFormat,
+        writer: impl Write,
+    ) -> Result<usize> {
+        let bookmarks = self
+            .get_user_bookmarks(user_id)
+            .tweet_fields([TweetField::AuthorId, TweetField::CreatedAt])
+            .user_fields([UserField::Name, UserField::Username])
+            .expansions([TweetExpansion::AuthorId])
+            .max_results(100)
+            .paginate();
+        export_tweets(bookmarks, format, writer).await
+    }
+}
@@ -0,0 +1,154 @@
+use super::TwitterApi;
+use crate::auth::Authorization;
+use crate::error::{Error, Result};
+use crate::rate_limit::{RateLimitMode, RateLimiter};
+use crate::retry::RetryPolicy;
+use reqwest::{Client, Proxy, Url};
+use std::sync::Arc;
+use std::time::Duration;
+
+const DEFAULT_BASE_URL: &str = "https://api.twitter.com/2/";
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Configures a [`TwitterApi`]. Connection settings (timeouts, proxy, user agent, pooling) are
+/// applied to the `reqwest::Client` built by [`build`](Self::build) and cannot be combined with
+/// a client passed in through [`client`](Self::client).
+#[derive(Debug)]
+pub struct TwitterApiBuilder<A> {
+    auth: A,
+    base_url: String,
+    client: Option<Client>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<Proxy>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    rate_limit_mode: Option<RateLimitMode>,
+    retry_policy: RetryPolicy,
+}
+
+impl<A> TwitterApiBuilder<A>
+where
+    A: Authorization,
+{
+    pub(crate) fn new(auth: A) -> Self {
+        Self {
+            auth,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            client: None,
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxy: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            rate_limit_mode: None,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Base URL all endpoints are resolved against, e.g. a local mock server.
+    pub fn base_url(mut self, base_url: impl ToString) -> Self {
+        self.base_url = base_url.to_string();
+        self
+    }
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+    pub fn user_agent(mut self, user_agent: impl ToString) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+    pub fn pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max_idle);
+        self
+    }
+    pub fn pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
+    pub fn rate_limit_mode(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limit_mode = Some(mode);
+        self
+    }
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn build(self) -> Result<TwitterApi<A>> {
+        let mut base_url = self.base_url;
+        // without the trailing slash `Url::join` would replace the last path segment
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        let base_url = Url::parse(&base_url)?;
+        if base_url.cannot_be_a_base() || !matches!(base_url.scheme(), "http" | "https") {
+            return Err(Error::custom(format!(
+                "base URL must be an http(s) URL, got {}",
+                base_url
+            )));
+        }
+
+        let client = match self.client {
+            Some(client) => {
+                if self.timeout.is_some()
+                    || self.connect_timeout.is_some()
+                    || self.user_agent.is_some()
+                    || self.proxy.is_some()
+                    || self.pool_max_idle_per_host.is_some()
+                    || self.pool_idle_timeout.is_some()
+                {
+                    return Err(Error::custom(
+                        "connection settings cannot be combined with a custom client",
+                    ));
+                }
+                client
+            }
+            None => {
+                let mut builder = Client::builder().user_agent(
+                    self.user_agent
+                        .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+                );
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                if let Some(max_idle) = self.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max_idle);
+                }
+                if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+                    builder = builder.pool_idle_timeout(pool_idle_timeout);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(TwitterApi::from_parts(
+            client,
+            base_url,
+            Arc::new(self.auth),
+            self.rate_limit_mode.map(RateLimiter::new),
+            self.retry_policy,
+        ))
+    }
+}
@@ -0,0 +1,66 @@
+use super::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::{FollowStatus, User};
+use crate::id::IntoNumericId;
+use crate::requests::UsersRequestBuilder;
+use reqwest::Method;
+use serde_json::json;
+
+impl<A> TwitterApi<A>
+where
+    A: Authorization,
+{
+    pub async fn follow_user(
+        &self,
+        user_id: impl IntoNumericId,
+        target_user_id: impl IntoNumericId,
+    ) -> ApiResult<FollowStatus> {
+        self.send(
+            self.request(
+                Method::POST,
+                self.url(format!("users/{}/following", user_id.into_id()))?,
+            )
+            .json(&json!({ "target_user_id": target_user_id.into_id() })),
+        )
+        .await
+    }
+
+    pub async fn unfollow_user(
+        &self,
+        user_id: impl IntoNumericId,
+        target_user_id: impl IntoNumericId,
+    ) -> ApiResult<FollowStatus> {
+        self.send(self.request(
+            Method::DELETE,
+            self.url(format!(
+                "users/{}/following/{}",
+                user_id.into_id(),
+                target_user_id.into_id()
+            ))?,
+        ))
+        .await
+    }
+
+    pub fn get_user_followers(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> UsersRequestBuilder<A, Vec<User>> {
+        UsersRequestBuilder::new(
+            self,
+            self.url(format!("users/{}/followers", user_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    pub fn get_user_following(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> UsersRequestBuilder<A, Vec<User>> {
+        UsersRequestBuilder::new(
+            self,
+            self.url(format!("users/{}/following", user_id.into_id()))
+                .unwrap(),
+        )
+    }
+}
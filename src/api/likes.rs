@@ -0,0 +1,66 @@
+use super::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::{LikeStatus, User};
+use crate::id::IntoNumericId;
+use crate::requests::{TimelineRequestBuilder, TweetList, UsersRequestBuilder};
+use reqwest::Method;
+use serde_json::json;
+
+impl<A> TwitterApi<A>
+where
+    A: Authorization,
+{
+    pub async fn like_tweet(
+        &self,
+        user_id: impl IntoNumericId,
+        tweet_id: impl IntoNumericId,
+    ) -> ApiResult<LikeStatus> {
+        self.send(
+            self.request(
+                Method::POST,
+                self.url(format!("users/{}/likes", user_id.into_id()))?,
+            )
+            .json(&json!({ "tweet_id": tweet_id.into_id() })),
+        )
+        .await
+    }
+
+    pub async fn unlike_tweet(
+        &self,
+        user_id: impl IntoNumericId,
+        tweet_id: impl IntoNumericId,
+    ) -> ApiResult<LikeStatus> {
+        self.send(self.request(
+            Method::DELETE,
+            self.url(format!(
+                "users/{}/likes/{}",
+                user_id.into_id(),
+                tweet_id.into_id()
+            ))?,
+        ))
+        .await
+    }
+
+    pub fn get_tweet_liking_users(
+        &self,
+        tweet_id: impl IntoNumericId,
+    ) -> UsersRequestBuilder<A, Vec<User>> {
+        UsersRequestBuilder::new(
+            self,
+            self.url(format!("tweets/{}/liking_users", tweet_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    pub fn get_user_liked_tweets(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> TimelineRequestBuilder<A, TweetList> {
+        TimelineRequestBuilder::new(
+            self,
+            self.url(format!("users/{}/liked_tweets", user_id.into_id()))
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,172 @@
+use super::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::{DeleteStatus, List, ListMemberStatus, PinStatus, UpdateStatus, User};
+use crate::id::IntoNumericId;
+use crate::requests::{
+    ListBuilder, ListsRequestBuilder, TimelineRequestBuilder, TweetList, UsersRequestBuilder,
+};
+use reqwest::Method;
+use serde_json::json;
+
+impl<A> TwitterApi<A>
+where
+    A: Authorization,
+{
+    pub fn get_list(&self, list_id: impl IntoNumericId) -> ListsRequestBuilder<A, List> {
+        ListsRequestBuilder::new(
+            self,
+            self.url(format!("lists/{}", list_id.into_id())).unwrap(),
+        )
+    }
+
+    /// Creates a List owned by the authenticated user. Only `id` and `name` are returned.
+    pub fn post_list(&self, name: impl ToString) -> ListBuilder<A, List> {
+        ListBuilder::new(self, Method::POST, self.url("lists").unwrap()).name(name)
+    }
+
+    pub fn put_list(&self, list_id: impl IntoNumericId) -> ListBuilder<A, UpdateStatus> {
+        ListBuilder::new(
+            self,
+            Method::PUT,
+            self.url(format!("lists/{}", list_id.into_id())).unwrap(),
+        )
+    }
+
+    pub async fn delete_list(&self, list_id: impl IntoNumericId) -> ApiResult<DeleteStatus> {
+        self.send(self.request(
+            Method::DELETE,
+            self.url(format!("lists/{}", list_id.into_id()))?,
+        ))
+        .await
+    }
+
+    pub async fn add_list_member(
+        &self,
+        list_id: impl IntoNumericId,
+        user_id: impl IntoNumericId,
+    ) -> ApiResult<ListMemberStatus> {
+        self.send(
+            self.request(
+                Method::POST,
+                self.url(format!("lists/{}/members", list_id.into_id()))?,
+            )
+            .json(&json!({ "user_id": user_id.into_id() })),
+        )
+        .await
+    }
+
+    pub async fn remove_list_member(
+        &self,
+        list_id: impl IntoNumericId,
+        user_id: impl IntoNumericId,
+    ) -> ApiResult<ListMemberStatus> {
+        self.send(self.request(
+            Method::DELETE,
+            self.url(format!(
+                "lists/{}/members/{}",
+                list_id.into_id(),
+                user_id.into_id()
+            ))?,
+        ))
+        .await
+    }
+
+    pub fn get_list_members(
+        &self,
+        list_id: impl IntoNumericId,
+    ) -> UsersRequestBuilder<A, Vec<User>> {
+        UsersRequestBuilder::new(
+            self,
+            self.url(format!("lists/{}/members", list_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    pub fn get_list_followers(
+        &self,
+        list_id: impl IntoNumericId,
+    ) -> UsersRequestBuilder<A, Vec<User>> {
+        UsersRequestBuilder::new(
+            self,
+            self.url(format!("lists/{}/followers", list_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    pub fn get_list_tweets(
+        &self,
+        list_id: impl IntoNumericId,
+    ) -> TimelineRequestBuilder<A, TweetList> {
+        TimelineRequestBuilder::new(
+            self,
+            self.url(format!("lists/{}/tweets", list_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    pub fn get_user_owned_lists(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> ListsRequestBuilder<A, Vec<List>> {
+        ListsRequestBuilder::new(
+            self,
+            self.url(format!("users/{}/owned_lists", user_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    /// Lists the user has been added to as a member.
+    pub fn get_user_list_memberships(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> ListsRequestBuilder<A, Vec<List>> {
+        ListsRequestBuilder::new(
+            self,
+            self.url(format!("users/{}/list_memberships", user_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    pub fn get_user_pinned_lists(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> ListsRequestBuilder<A, Vec<List>> {
+        ListsRequestBuilder::new(
+            self,
+            self.url(format!("users/{}/pinned_lists", user_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    pub async fn pin_list(
+        &self,
+        user_id: impl IntoNumericId,
+        list_id: impl IntoNumericId,
+    ) -> ApiResult<PinStatus> {
+        self.send(
+            self.request(
+                Method::POST,
+                self.url(format!("users/{}/pinned_lists", user_id.into_id()))?,
+            )
+            .json(&json!({ "list_id": list_id.into_id() })),
+        )
+        .await
+    }
+
+    pub async fn unpin_list(
+        &self,
+        user_id: impl IntoNumericId,
+        list_id: impl IntoNumericId,
+    ) -> ApiResult<PinStatus> {
+        self.send(self.request(
+            Method::DELETE,
+            self.url(format!(
+                "users/{}/pinned_lists/{}",
+                user_id.into_id(),
+                list_id.into_id()
+            ))?,
+        ))
+        .await
+    }
+}
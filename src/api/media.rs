@@ -0,0 +1,125 @@
+use super::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::{
+    MediaAltText, MediaMetadataStatus, MediaSubtitle, MediaSubtitles, VideoCategory,
+};
+use crate::error::{Error, Result};
+use crate::id::IntoNumericId;
+use crate::requests::MediaUploadBuilder;
+use reqwest::Method;
+use serde_json::json;
+use std::path::Path;
+use tokio::io::AsyncRead;
+
+impl<A> TwitterApi<A>
+where
+    A: Authorization,
+{
+    /// Uploads in-memory media of the given MIME type, e.g. `image/png`.
+    pub fn upload_media(
+        &self,
+        bytes: impl Into<Vec<u8>>,
+        media_type: impl ToString,
+    ) -> MediaUploadBuilder<A> {
+        MediaUploadBuilder::from_bytes(self, bytes.into(), media_type)
+    }
+
+    /// Uploads a file, guessing its MIME type from the extension.
+    ///
+    /// ```no_run
+    /// # use tweetterminal::{auth::BearerToken, TwitterApi};
+    /// # async fn example(api: TwitterApi<BearerToken>) -> tweetterminal::Result<()> {
+    /// let chart = api.upload_media_file("chart.png").send().await?;
+    /// let media_id = chart.data().expect("uploaded media").id;
+    /// api.post_tweet()
+    ///     .text("Today's numbers".to_string())
+    ///     .add_media([media_id], Vec::<u64>::new())
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload_media_file(&self, path: impl AsRef<Path>) -> MediaUploadBuilder<A> {
+        MediaUploadBuilder::from_file(self, path.as_ref().to_path_buf())
+    }
+
+    /// Uploads `total_bytes` of media read from `reader`. Chunked uploads stream the reader
+    /// instead of buffering it. The upload fails if the reader ends early or yields more.
+    pub fn upload_media_reader(
+        &self,
+        reader: impl AsyncRead + Send + 'static,
+        total_bytes: u64,
+        media_type: impl ToString,
+    ) -> MediaUploadBuilder<A> {
+        MediaUploadBuilder::from_reader(self, reader, total_bytes, media_type)
+    }
+
+    /// Sets the alt text screen readers announce for an uploaded image or GIF. Fails without a
+    /// request if it is longer than [`MediaAltText::MAX_LEN`] characters.
+    pub async fn set_media_alt_text(
+        &self,
+        media_id: impl IntoNumericId,
+        alt_text: impl ToString,
+    ) -> ApiResult<MediaMetadataStatus> {
+        let alt_text = MediaAltText {
+            text: alt_text.to_string(),
+        };
+        check_alt_text(&alt_text.text)?;
+        self.send(
+            self.request(Method::POST, self.url("media/metadata")?)
+                .json(&json!({
+                    "id": media_id.into_id(),
+                    "metadata": { "alt_text": alt_text },
+                })),
+        )
+        .await
+    }
+
+    /// Attaches an uploaded subtitle track to an uploaded video of the given category.
+    pub async fn add_media_subtitles(
+        &self,
+        video_id: impl IntoNumericId,
+        category: VideoCategory,
+        subtitle: MediaSubtitle,
+    ) -> ApiResult<MediaSubtitles> {
+        self.send(
+            self.request(Method::POST, self.url("media/subtitles")?)
+                .json(&json!({
+                    "id": video_id.into_id(),
+                    "media_category": category,
+                    "subtitles": subtitle,
+                })),
+        )
+        .await
+    }
+
+    pub async fn delete_media_subtitles(
+        &self,
+        video_id: impl IntoNumericId,
+        category: VideoCategory,
+        language_code: impl ToString,
+    ) -> ApiResult<MediaSubtitles> {
+        self.send(
+            self.request(Method::DELETE, self.url("media/subtitles")?)
+                .json(&json!({
+                    "id": video_id.into_id(),
+                    "media_category": category,
+                    "language_code": language_code.to_string(),
+                })),
+        )
+        .await
+    }
+}
+
+pub(crate) fn check_alt_text(alt_text: &str) -> Result<()> {
+    let len = alt_text.chars().count();
+    if len > MediaAltText::MAX_LEN {
+        return Err(Error::custom(format!(
+            "Alt text is {} characters long, the limit is {}",
+            len,
+            MediaAltText::MAX_LEN
+        )));
+    }
+    Ok(())
+}
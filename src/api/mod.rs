@@ -1,6 +1,21 @@
 mod base;
+mod blocks;
+mod bookmarks;
+mod builder;
+mod follows;
+mod likes;
+mod lists;
+mod media;
+mod mutes;
+mod retweets;
+mod search;
+mod timelines;
 mod tweets;
+mod users;
 mod with_user_ctx;
 
 pub use base::TwitterApi;
+pub use builder::TwitterApiBuilder;
 pub use with_user_ctx::TwitterApiWithUserCtx;
+
+pub(crate) use media::check_alt_text;
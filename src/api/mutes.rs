@@ -0,0 +1,55 @@
+use super::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::{MuteStatus, User};
+use crate::id::IntoNumericId;
+use crate::requests::UsersRequestBuilder;
+use reqwest::Method;
+use serde_json::json;
+
+impl<A> TwitterApi<A>
+where
+    A: Authorization,
+{
+    pub async fn mute_user(
+        &self,
+        user_id: impl IntoNumericId,
+        target_user_id: impl IntoNumericId,
+    ) -> ApiResult<MuteStatus> {
+        self.send(
+            self.request(
+                Method::POST,
+                self.url(format!("users/{}/muting", user_id.into_id()))?,
+            )
+            .json(&json!({ "target_user_id": target_user_id.into_id() })),
+        )
+        .await
+    }
+
+    pub async fn unmute_user(
+        &self,
+        user_id: impl IntoNumericId,
+        target_user_id: impl IntoNumericId,
+    ) -> ApiResult<MuteStatus> {
+        self.send(self.request(
+            Method::DELETE,
+            self.url(format!(
+                "users/{}/muting/{}",
+                user_id.into_id(),
+                target_user_id.into_id()
+            ))?,
+        ))
+        .await
+    }
+
+    pub fn get_user_muting(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> UsersRequestBuilder<A, Vec<User>> {
+        UsersRequestBuilder::new(
+            self,
+            self.url(format!("users/{}/muting", user_id.into_id()))
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,66 @@
+use super::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::{RetweetStatus, User};
+use crate::id::IntoNumericId;
+use crate::requests::{TimelineRequestBuilder, TweetList, UsersRequestBuilder};
+use reqwest::Method;
+use serde_json::json;
+
+impl<A> TwitterApi<A>
+where
+    A: Authorization,
+{
+    pub async fn retweet(
+        &self,
+        user_id: impl IntoNumericId,
+        tweet_id: impl IntoNumericId,
+    ) -> ApiResult<RetweetStatus> {
+        self.send(
+            self.request(
+                Method::POST,
+                self.url(format!("users/{}/retweets", user_id.into_id()))?,
+            )
+            .json(&json!({ "tweet_id": tweet_id.into_id() })),
+        )
+        .await
+    }
+
+    pub async fn unretweet(
+        &self,
+        user_id: impl IntoNumericId,
+        source_tweet_id: impl IntoNumericId,
+    ) -> ApiResult<RetweetStatus> {
+        self.send(self.request(
+            Method::DELETE,
+            self.url(format!(
+                "users/{}/retweets/{}",
+                user_id.into_id(),
+                source_tweet_id.into_id()
+            ))?,
+        ))
+        .await
+    }
+
+    pub fn get_tweet_retweeted_by(
+        &self,
+        tweet_id: impl IntoNumericId,
+    ) -> UsersRequestBuilder<A, Vec<User>> {
+        UsersRequestBuilder::new(
+            self,
+            self.url(format!("tweets/{}/retweeted_by", tweet_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    pub fn get_tweet_quote_tweets(
+        &self,
+        tweet_id: impl IntoNumericId,
+    ) -> TimelineRequestBuilder<A, TweetList> {
+        TimelineRequestBuilder::new(
+            self,
+            self.url(format!("tweets/{}/quote_tweets", tweet_id.into_id()))
+                .unwrap(),
+        )
+    }
+}
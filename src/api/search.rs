@@ -0,0 +1,73 @@
+use super::TwitterApi;
+use crate::auth::Authorization;
+use crate::error::Result;
+use crate::query::{SearchQuery, UrlQueryExt};
+use crate::requests::{TweetCountsRequestBuilder, TweetsSearchRequestBuilder};
+
+impl<A> TwitterApi<A>
+where
+    A: Authorization,
+{
+    /// Tweets of the last seven days matching `query`.
+    pub fn search_recent(&self, query: impl ToString) -> TweetsSearchRequestBuilder<A> {
+        let mut url = self.url("tweets/search/recent").unwrap();
+        url.replace_query_val("query", query);
+        TweetsSearchRequestBuilder::new(self, url)
+    }
+
+    /// Tweets matching `query` since 2006. Requires full-archive access.
+    pub fn search_all(&self, query: impl ToString) -> TweetsSearchRequestBuilder<A> {
+        let mut url = self.url("tweets/search/all").unwrap();
+        url.replace_query_val("query", query);
+        TweetsSearchRequestBuilder::new(self, url)
+    }
+
+    /// Volume of Tweets matching `query` over the last seven days.
+    pub fn tweet_counts_recent(&self, query: impl ToString) -> TweetCountsRequestBuilder<A> {
+        let mut url = self.url("tweets/counts/recent").unwrap();
+        url.replace_query_val("query", query);
+        TweetCountsRequestBuilder::new(self, url)
+    }
+
+    /// Volume of Tweets matching `query` since 2006. Requires full-archive access.
+    pub fn tweet_counts_all(&self, query: impl ToString) -> TweetCountsRequestBuilder<A> {
+        let mut url = self.url("tweets/counts/all").unwrap();
+        url.replace_query_val("query", query);
+        TweetCountsRequestBuilder::new(self, url)
+    }
+
+    /// Like [`search_recent`](Self::search_recent), but checks `query` against the rules and
+    /// length limit of recent search before anything is sent.
+    pub fn search_recent_query(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<TweetsSearchRequestBuilder<A>> {
+        query.validate(SearchQuery::MAX_LEN)?;
+        Ok(self.search_recent(query))
+    }
+
+    /// Like [`search_all`](Self::search_all), but checks `query` against the rules and length
+    /// limit of full-archive search before anything is sent.
+    pub fn search_all_query(&self, query: &SearchQuery) -> Result<TweetsSearchRequestBuilder<A>> {
+        query.validate(SearchQuery::MAX_LEN_FULL_ARCHIVE)?;
+        Ok(self.search_all(query))
+    }
+
+    /// Like [`tweet_counts_recent`](Self::tweet_counts_recent), with `query` checked locally.
+    pub fn tweet_counts_recent_query(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<TweetCountsRequestBuilder<A>> {
+        query.validate(SearchQuery::MAX_LEN)?;
+        Ok(self.tweet_counts_recent(query))
+    }
+
+    /// Like [`tweet_counts_all`](Self::tweet_counts_all), with `query` checked locally.
+    pub fn tweet_counts_all_query(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<TweetCountsRequestBuilder<A>> {
+        query.validate(SearchQuery::MAX_LEN_FULL_ARCHIVE)?;
+        Ok(self.tweet_counts_all(query))
+    }
+}
@@ -0,0 +1,47 @@
+use super::TwitterApi;
+use crate::auth::Authorization;
+use crate::id::IntoNumericId;
+use crate::requests::{MentionsTimeline, TimelineRequestBuilder, UserTimeline};
+
+impl<A> TwitterApi<A>
+where
+    A: Authorization,
+{
+    /// Tweets and Retweets of the user and the accounts they follow, newest first. Only
+    /// available for the authenticated user.
+    pub fn get_user_home_timeline(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> TimelineRequestBuilder<A, UserTimeline> {
+        TimelineRequestBuilder::new(
+            self,
+            self.url(format!(
+                "users/{}/timelines/reverse_chronological",
+                user_id.into_id()
+            ))
+            .unwrap(),
+        )
+    }
+
+    pub fn get_user_tweets(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> TimelineRequestBuilder<A, UserTimeline> {
+        TimelineRequestBuilder::new(
+            self,
+            self.url(format!("users/{}/tweets", user_id.into_id()))
+                .unwrap(),
+        )
+    }
+
+    pub fn get_user_mentions(
+        &self,
+        user_id: impl IntoNumericId,
+    ) -> TimelineRequestBuilder<A, MentionsTimeline> {
+        TimelineRequestBuilder::new(
+            self,
+            self.url(format!("users/{}/mentions", user_id.into_id()))
+                .unwrap(),
+        )
+    }
+}
@@ -1,23 +1,56 @@
 use super::TwitterApi;
 use crate::api_result::ApiResult;
 use crate::auth::Authorization;
+use crate::data::{DeleteStatus, HideReplyStatus, Tweet};
 use crate::id::IntoNumericId;
-use crate::requests::TweetBuilder;
+use crate::query::UrlQueryExt;
+use crate::requests::{TweetBuilder, TweetsRequestBuilder};
 use reqwest::Method;
+use serde_json::json;
 
 impl<A> TwitterApi<A>
 where
     A: Authorization,
 {
+    pub fn get_tweet(&self, id: impl IntoNumericId) -> TweetsRequestBuilder<A, Tweet> {
+        TweetsRequestBuilder::new(self, self.url(format!("tweets/{}", id.into_id())).unwrap())
+    }
+
+    pub fn get_tweets(
+        &self,
+        ids: impl IntoIterator<Item = impl IntoNumericId>,
+    ) -> TweetsRequestBuilder<A, Vec<Tweet>> {
+        let mut url = self.url("tweets").unwrap();
+        url.replace_query_seq("ids", ids.into_iter().map(|id| id.into_id()));
+        TweetsRequestBuilder::new(self, url)
+    }
+
     pub fn post_tweet(&self) -> TweetBuilder<A> {
         TweetBuilder::new(self, self.url("tweets").unwrap())
     }
 
-    pub async fn delete_tweet(&self, id: impl IntoNumericId) -> ApiResult<()> {
+    pub async fn delete_tweet(&self, id: impl IntoNumericId) -> ApiResult<DeleteStatus> {
         self.send(self.request(
             Method::DELETE,
             self.url(format!("tweets/{}", id.into_id()))?,
         ))
         .await
     }
+
+    /// Hides (or unhides) a reply to a conversation started by the authenticated user. Requires
+    /// the `tweet.moderate.write` scope.
+    pub async fn hide_reply(
+        &self,
+        tweet_id: impl IntoNumericId,
+        hidden: bool,
+    ) -> ApiResult<HideReplyStatus> {
+        self.send(
+            self.request(
+                Method::PUT,
+                self.url(format!("tweets/{}/hidden", tweet_id.into_id()))?,
+            )
+            .json(&json!({ "hidden": hidden })),
+        )
+        .await
+    }
 }
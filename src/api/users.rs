@@ -0,0 +1,52 @@
+use super::TwitterApi;
+use crate::auth::Authorization;
+use crate::data::User;
+use crate::id::IntoNumericId;
+use crate::query::UrlQueryExt;
+use crate::requests::UsersRequestBuilder;
+
+impl<A> TwitterApi<A>
+where
+    A: Authorization,
+{
+    pub fn get_user(&self, id: impl IntoNumericId) -> UsersRequestBuilder<A, User> {
+        UsersRequestBuilder::new(self, self.url(format!("users/{}", id.into_id())).unwrap())
+    }
+
+    pub fn get_users(
+        &self,
+        ids: impl IntoIterator<Item = impl IntoNumericId>,
+    ) -> UsersRequestBuilder<A, Vec<User>> {
+        let mut url = self.url("users").unwrap();
+        url.replace_query_seq("ids", ids.into_iter().map(|id| id.into_id()));
+        UsersRequestBuilder::new(self, url)
+    }
+
+    pub fn get_user_by_username(&self, username: impl AsRef<str>) -> UsersRequestBuilder<A, User> {
+        let mut url = self.url("users/by/username/").unwrap();
+        url.path_segments_mut()
+            .unwrap()
+            .pop_if_empty()
+            .push(username.as_ref().trim_start_matches('@'));
+        UsersRequestBuilder::new(self, url)
+    }
+
+    pub fn get_users_by_usernames(
+        &self,
+        usernames: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> UsersRequestBuilder<A, Vec<User>> {
+        let mut url = self.url("users/by").unwrap();
+        url.replace_query_seq(
+            "usernames",
+            usernames
+                .into_iter()
+                .map(|username| username.as_ref().trim_start_matches('@').to_string()),
+        );
+        UsersRequestBuilder::new(self, url)
+    }
+
+    /// The user the client is authenticated as. Not available with app-only bearer tokens.
+    pub fn get_users_me(&self) -> UsersRequestBuilder<A, User> {
+        UsersRequestBuilder::new(self, self.url("users/me").unwrap())
+    }
+}
@@ -1,25 +1,163 @@
 use super::TwitterApi;
+use crate::api_result::ApiResult;
 use crate::auth::Authorization;
-use crate::error::Result;
-use crate::id::NumericId;
+use crate::data::{
+    BlockStatus, BookmarkStatus, FollowStatus, LikeStatus, List, MuteStatus, PinStatus,
+    RetweetStatus, User,
+};
+use crate::error::{Error, Result};
+use crate::export::ExportFormat;
+use crate::id::{IntoNumericId, NumericId};
+use crate::requests::{
+    ListsRequestBuilder, MentionsTimeline, TimelineRequestBuilder, TweetList, UserTimeline,
+    UsersRequestBuilder,
+};
+use std::io::Write;
 
+/// Client bound to the authenticated user, for endpoints that need the caller's own id in the
+/// path.
+#[derive(Debug)]
 pub struct TwitterApiWithUserCtx<A> {
-    #[allow(dead_code)]
     user_id: NumericId,
-    #[allow(dead_code)]
     client: TwitterApi<A>,
 }
 
 impl<A> TwitterApi<A>
 where
-    A: Authorization + Clone,
+    A: Authorization,
 {
+    /// Resolves the authenticated user through `users/me`.
     pub async fn with_user_ctx(&self) -> Result<TwitterApiWithUserCtx<A>> {
-        // TODO: Implement actual user ID retrieval
-        let user_id = NumericId::new(0);
+        let user = self
+            .get_users_me()
+            .send()
+            .await?
+            .into_data()
+            .ok_or_else(|| Error::custom("users/me returned no user"))?;
         Ok(TwitterApiWithUserCtx {
-            user_id,
-            client: TwitterApi::new(self.auth().clone()),
+            user_id: user.id,
+            client: self.clone(),
         })
     }
 }
+
+impl<A> TwitterApiWithUserCtx<A>
+where
+    A: Authorization,
+{
+    pub fn user_id(&self) -> NumericId {
+        self.user_id
+    }
+
+    pub fn client(&self) -> &TwitterApi<A> {
+        &self.client
+    }
+
+    pub fn get_me(&self) -> UsersRequestBuilder<A, User> {
+        self.client.get_user(self.user_id)
+    }
+
+    pub async fn like_tweet(&self, tweet_id: impl IntoNumericId) -> ApiResult<LikeStatus> {
+        self.client.like_tweet(self.user_id, tweet_id).await
+    }
+    pub async fn unlike_tweet(&self, tweet_id: impl IntoNumericId) -> ApiResult<LikeStatus> {
+        self.client.unlike_tweet(self.user_id, tweet_id).await
+    }
+    pub fn get_my_liked_tweets(&self) -> TimelineRequestBuilder<A, TweetList> {
+        self.client.get_user_liked_tweets(self.user_id)
+    }
+
+    pub async fn retweet(&self, tweet_id: impl IntoNumericId) -> ApiResult<RetweetStatus> {
+        self.client.retweet(self.user_id, tweet_id).await
+    }
+    pub async fn unretweet(&self, tweet_id: impl IntoNumericId) -> ApiResult<RetweetStatus> {
+        self.client.unretweet(self.user_id, tweet_id).await
+    }
+
+    pub async fn follow_user(&self, user_id: impl IntoNumericId) -> ApiResult<FollowStatus> {
+        self.client.follow_user(self.user_id, user_id).await
+    }
+    pub async fn unfollow_user(&self, user_id: impl IntoNumericId) -> ApiResult<FollowStatus> {
+        self.client.unfollow_user(self.user_id, user_id).await
+    }
+    pub fn get_my_followers(&self) -> UsersRequestBuilder<A, Vec<User>> {
+        self.client.get_user_followers(self.user_id)
+    }
+    pub fn get_my_following(&self) -> UsersRequestBuilder<A, Vec<User>> {
+        self.client.get_user_following(self.user_id)
+    }
+
+    pub async fn block_user(&self, user_id: impl IntoNumericId) -> ApiResult<BlockStatus> {
+        self.client.block_user(self.user_id, user_id).await
+    }
+    pub async fn unblock_user(&self, user_id: impl IntoNumericId) -> ApiResult<BlockStatus> {
+        self.client.unblock_user(self.user_id, user_id).await
+    }
+    pub fn get_my_blocking(&self) -> UsersRequestBuilder<A, Vec<User>> {
+        self.client.get_user_blocking(self.user_id)
+    }
+
+    pub async fn mute_user(&self, user_id: impl IntoNumericId) -> ApiResult<MuteStatus> {
+        self.client.mute_user(self.user_id, user_id).await
+    }
+    pub async fn unmute_user(&self, user_id: impl IntoNumericId) -> ApiResult<MuteStatus> {
+        self.client.unmute_user(self.user_id, user_id).await
+    }
+    pub fn get_my_muting(&self) -> UsersRequestBuilder<A, Vec<User>> {
+        self.client.get_user_muting(self.user_id)
+    }
+
+    pub async fn bookmark_tweet(&self, tweet_id: impl IntoNumericId) -> ApiResult<BookmarkStatus> {
+        self.client.bookmark_tweet(self.user_id, tweet_id).await
+    }
+    pub async fn remove_bookmark(&self, tweet_id: impl IntoNumericId) -> ApiResult<BookmarkStatus> {
+        self.client.remove_bookmark(self.user_id, tweet_id).await
+    }
+    pub fn get_my_bookmarks(&self) -> TimelineRequestBuilder<A, TweetList> {
+        self.client.get_user_bookmarks(self.user_id)
+    }
+    pub async fn export_bookmarks(
+        &self,
+        format: ExportFormat,
+        writer: impl Write,
+    ) -> Result<usize> {
+        self.client
+            .export_user_bookmarks(self.user_id, format, writer)
+            .await
+    }
+
+    pub fn get_my_owned_lists(&self) -> ListsRequestBuilder<A, Vec<List>> {
+        self.client.get_user_owned_lists(self.user_id)
+    }
+    pub fn get_my_list_memberships(&self) -> ListsRequestBuilder<A, Vec<List>> {
+        self.client.get_user_list_memberships(self.user_id)
+    }
+    pub fn get_my_pinned_lists(&self) -> ListsRequestBuilder<A, Vec<List>> {
+        self.client.get_user_pinned_lists(self.user_id)
+    }
+    pub async fn pin_list(&self, list_id: impl IntoNumericId) -> ApiResult<PinStatus> {
+        self.client.pin_list(self.user_id, list_id).await
+    }
+    pub async fn unpin_list(&self, list_id: impl IntoNumericId) -> ApiResult<PinStatus> {
+        self.client.unpin_list(self.user_id, list_id).await
+    }
+
+    pub fn get_home_timeline(&self) -> TimelineRequestBuilder<A, UserTimeline> {
+        self.client.get_user_home_timeline(self.user_id)
+    }
+    pub fn get_my_tweets(&self) -> TimelineRequestBuilder<A, UserTimeline> {
+        self.client.get_user_tweets(self.user_id)
+    }
+    pub fn get_my_mentions(&self) -> TimelineRequestBuilder<A, MentionsTimeline> {
+        self.client.get_user_mentions(self.user_id)
+    }
+}
+
+impl<A> Clone for TwitterApiWithUserCtx<A> {
+    fn clone(&self) -> Self {
+        Self {
+            user_id: self.user_id,
+            client: self.client.clone(),
+        }
+    }
+}
@@ -1,50 +1,486 @@
+use crate::data::{Media, Place, Poll, Tweet, User};
+use crate::error::Result;
+use crate::id::{IntoNumericId, NumericId, StringId};
+use crate::rate_limit::RateLimit;
+use async_trait::async_trait;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use thiserror::Error;
 
 #[derive(Debug, Error, Clone, Serialize, Deserialize)]
-pub enum ApiError {
-    #[error("API Error: {message}")]
-    General { message: String },
-    #[error("Rate limit exceeded")]
-    RateLimit,
-    #[error("Unauthorized")]
-    Unauthorized,
-    #[error("Not found")]
-    NotFound,
+#[error("[{status}] {title}: {detail}")]
+pub struct ApiError {
+    #[serde(with = "serde_status_code")]
+    pub status: StatusCode,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub title: String,
+    pub detail: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimit>,
+}
+
+impl ApiError {
+    pub fn is_unauthorized(&self) -> bool {
+        self.status == StatusCode::UNAUTHORIZED
+    }
+
+    pub fn is_forbidden(&self) -> bool {
+        self.status == StatusCode::FORBIDDEN
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status == StatusCode::NOT_FOUND
+    }
+
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == StatusCode::TOO_MANY_REQUESTS
+    }
+
+    pub fn is_server_error(&self) -> bool {
+        self.status.is_server_error()
+    }
+
+    /// Twitter rejects a repeated Tweet with a plain 403, so the only way to tell it apart from
+    /// a missing permission is the detail message.
+    pub fn is_duplicate_content(&self) -> bool {
+        self.is_forbidden() && self.detail.to_lowercase().contains("duplicate content")
+    }
+
+    fn from_body(status: StatusCode, rate_limit: Option<RateLimit>, body: &str) -> Self {
+        let reason = status.canonical_reason().unwrap_or("Unknown").to_string();
+        match serde_json::from_str::<ApiProblem>(body) {
+            Ok(problem) => {
+                let message = problem.errors.into_iter().find_map(|error| error.message);
+                Self {
+                    status,
+                    kind: problem.kind.unwrap_or_else(|| "about:blank".to_string()),
+                    title: problem.title.unwrap_or_else(|| reason.clone()),
+                    detail: problem.detail.or(message).unwrap_or(reason),
+                    rate_limit,
+                }
+            }
+            Err(_) => Self {
+                status,
+                kind: "about:blank".to_string(),
+                title: reason.clone(),
+                detail: if body.trim().is_empty() {
+                    reason
+                } else {
+                    body.trim().to_string()
+                },
+                rate_limit,
+            },
+        }
+    }
+}
+
+/// Problem body as returned by the API. Every field is optional since some failures (mostly
+/// gateway errors) come back with a different shape or no body at all.
+#[derive(Deserialize)]
+struct ApiProblem {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    title: Option<String>,
+    detail: Option<String>,
+    #[serde(default)]
+    errors: Vec<ApiProblemMessage>,
+}
+
+#[derive(Deserialize)]
+struct ApiProblemMessage {
+    message: Option<String>,
+}
+
+/// Error for a single item of an otherwise successful response, e.g. one deleted Tweet in a
+/// lookup of many ids or a duplicate filtered stream rule. Which fields are set depends on the
+/// endpoint.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ApiPayloadError {
+    /// Id of the existing stream rule, for rules that could not be created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<StringId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+}
+
+impl ApiPayloadError {
+    pub fn is_not_found(&self) -> bool {
+        self.has_kind("/resource-not-found")
+    }
+
+    pub fn is_not_authorized(&self) -> bool {
+        self.has_kind("/not-authorized-for-resource")
+    }
+
+    fn has_kind(&self, suffix: &str) -> bool {
+        self.kind
+            .as_deref()
+            .is_some_and(|kind| kind.ends_with(suffix))
+    }
+}
+
+impl fmt::Display for ApiPayloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let title = self.title.as_deref().or(self.kind.as_deref());
+        match (title, self.detail.as_deref()) {
+            (Some(title), Some(detail)) => write!(f, "{}: {}", title, detail),
+            (Some(message), None) | (None, Some(message)) => f.write_str(message),
+            (None, None) => f.write_str("Unknown error"),
+        }
+    }
+}
+
+/// Meta returned by paginated and search endpoints. Lookups that return no meta at all simply
+/// leave `ApiPayload::meta` empty.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PaginationMeta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub newest_id: Option<StringId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest_id: Option<StringId>,
+}
+
+/// Objects referenced by `data` that were pulled in through `expansions`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Includes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<User>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tweets: Option<Vec<Tweet>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media: Option<Vec<Media>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub places: Option<Vec<Place>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub polls: Option<Vec<Poll>>,
+}
+
+impl Includes {
+    pub fn user(&self, id: impl IntoNumericId) -> Option<&User> {
+        let id = id.into_id();
+        self.users.as_deref()?.iter().find(|user| user.id == id)
+    }
+
+    pub fn tweet(&self, id: &StringId) -> Option<&Tweet> {
+        self.tweets.as_deref()?.iter().find(|tweet| &tweet.id == id)
+    }
+
+    pub fn media(&self, media_key: &str) -> Option<&Media> {
+        self.media
+            .as_deref()?
+            .iter()
+            .find(|media| media.media_key == media_key)
+    }
+
+    pub fn place(&self, id: &StringId) -> Option<&Place> {
+        self.places.as_deref()?.iter().find(|place| &place.id == id)
+    }
+
+    pub fn poll(&self, id: &str) -> Option<&Poll> {
+        self.polls.as_deref()?.iter().find(|poll| poll.id == id)
+    }
+
+    /// Requires the `author_id` expansion.
+    pub fn tweet_author(&self, tweet: &Tweet) -> Option<&User> {
+        let author_id = tweet
+            .author_id
+            .as_ref()?
+            .as_str()
+            .parse::<NumericId>()
+            .ok()?;
+        self.user(author_id)
+    }
+
+    /// Requires the `attachments.media_keys` expansion. Keys missing from the includes are
+    /// skipped.
+    pub fn tweet_media(&self, tweet: &Tweet) -> Vec<&Media> {
+        tweet
+            .attachments
+            .as_ref()
+            .and_then(|attachments| attachments.media_keys.as_deref())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|media_key| self.media(media_key))
+            .collect()
+    }
+
+    /// Requires the `attachments.poll_ids` expansion.
+    pub fn tweet_polls(&self, tweet: &Tweet) -> Vec<&Poll> {
+        tweet
+            .attachments
+            .as_ref()
+            .and_then(|attachments| attachments.poll_ids.as_deref())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|poll_id| self.poll(poll_id))
+            .collect()
+    }
+
+    /// Requires the `geo.place_id` expansion.
+    pub fn tweet_place(&self, tweet: &Tweet) -> Option<&Place> {
+        self.place(tweet.geo.as_ref()?.place_id.as_ref()?)
+    }
+
+    /// Requires the `referenced_tweets.id` expansion.
+    pub fn referenced_tweets(&self, tweet: &Tweet) -> Vec<&Tweet> {
+        tweet
+            .referenced_tweets
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|referenced| self.tweet(&referenced.id))
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct ApiPayload<T> {
+pub struct ApiPayload<T, M = PaginationMeta> {
     pub data: Option<T>,
-    pub meta: Option<serde_json::Value>,
-    pub errors: Option<Vec<ApiError>>,
+    pub includes: Option<Includes>,
+    pub meta: Option<M>,
+    pub errors: Option<Vec<ApiPayloadError>>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct ApiResponse<T> {
-    pub payload: ApiPayload<T>,
+pub struct ApiResponse<T, M = PaginationMeta> {
+    pub payload: ApiPayload<T, M>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimit>,
 }
 
-impl<T> ApiResponse<T> {
-    pub fn new(payload: ApiPayload<T>) -> Self {
-        Self { payload }
+impl<T, M> ApiResponse<T, M> {
+    pub fn new(payload: ApiPayload<T, M>) -> Self {
+        Self {
+            payload,
+            rate_limit: None,
+        }
+    }
+
+    pub fn with_rate_limit(mut self, rate_limit: Option<RateLimit>) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    pub fn data(&self) -> Option<&T> {
+        self.payload.data.as_ref()
+    }
+
+    pub fn into_data(self) -> Option<T> {
+        self.payload.data
+    }
+
+    pub fn includes(&self) -> Option<&Includes> {
+        self.payload.includes.as_ref()
+    }
+
+    pub fn meta(&self) -> Option<&M> {
+        self.payload.meta.as_ref()
+    }
+
+    /// Per-item errors returned next to `data`. Empty when every item could be resolved.
+    pub fn errors(&self) -> &[ApiPayloadError] {
+        self.payload.errors.as_deref().unwrap_or_default()
     }
 }
 
-pub type ApiResult<T> = Result<ApiResponse<T>, crate::error::Error>;
+pub type ApiResult<T, M = PaginationMeta> = Result<ApiResponse<T, M>, crate::error::Error>;
 
-pub trait ApiResponseExt {
-    fn api_error_for_status(self) -> Self;
+#[async_trait]
+pub trait ApiResponseExt: Sized {
+    async fn api_error_for_status(self) -> Result<Self>;
 }
 
+#[async_trait]
 impl ApiResponseExt for reqwest::Response {
-    fn api_error_for_status(self) -> Self {
-        // For now, just return the response as is
-        // This should be implemented to check for API errors
-        self
+    async fn api_error_for_status(self) -> Result<Self> {
+        let status = self.status();
+        if status.is_success() {
+            Ok(self)
+        } else {
+            let rate_limit = RateLimit::from_headers(self.headers());
+            let body = self.text().await?;
+            tracing::debug!("Twitter API error response ({}): {}", status, body);
+            Err(ApiError::from_body(status, rate_limit, &body).into())
+        }
     }
 }
 
 pub trait PaginableApiResponse<T> {
     fn into_data(self) -> Option<T>;
+    /// Token of the following page, `None` on the last page.
+    fn next_token(&self) -> Option<&str>;
+}
+
+impl<T> PaginableApiResponse<T> for ApiResponse<T, PaginationMeta> {
+    fn into_data(self) -> Option<T> {
+        self.payload.data
+    }
+
+    fn next_token(&self) -> Option<&str> {
+        self.payload.meta.as_ref()?.next_token.as_deref()
+    }
+}
+
+mod serde_status_code {
+    use reqwest::StatusCode;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(status: &StatusCode, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u16(status.as_u16())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<StatusCode, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        StatusCode::from_u16(u16::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::{StreamRule, StreamRuleMeta};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn error_from_problem() {
+        let error = ApiError::from_body(
+            StatusCode::UNAUTHORIZED,
+            None,
+            r#"{"title": "Unauthorized", "type": "about:blank", "status": 401, "detail": "Unauthorized"}"#,
+        );
+        assert!(error.is_unauthorized());
+        assert_eq!(error.kind, "about:blank");
+        assert_eq!(error.title, "Unauthorized");
+        assert_eq!(error.detail, "Unauthorized");
+    }
+
+    #[test]
+    fn error_from_empty_body() {
+        let error = ApiError::from_body(StatusCode::BAD_GATEWAY, None, " ");
+        assert!(error.is_server_error());
+        assert_eq!(error.title, "Bad Gateway");
+        assert_eq!(error.detail, "Bad Gateway");
+    }
+
+    #[test]
+    fn error_from_text_body() {
+        let error = ApiError::from_body(StatusCode::SERVICE_UNAVAILABLE, None, "Over capacity\n");
+        assert_eq!(error.kind, "about:blank");
+        assert_eq!(error.title, "Service Unavailable");
+        assert_eq!(error.detail, "Over capacity");
+    }
+
+    #[test]
+    fn error_from_v1_messages() {
+        let error = ApiError::from_body(
+            StatusCode::TOO_MANY_REQUESTS,
+            None,
+            r#"{"errors": [{"code": 88, "message": "Rate limit exceeded"}]}"#,
+        );
+        assert!(error.is_rate_limited());
+        assert_eq!(error.title, "Too Many Requests");
+        assert_eq!(error.detail, "Rate limit exceeded");
+    }
+
+    #[test]
+    fn error_for_duplicate_content() {
+        let error = ApiError::from_body(
+            StatusCode::FORBIDDEN,
+            None,
+            r#"{"detail": "You are not allowed to create a Tweet with duplicate content.", "type": "about:blank", "title": "Forbidden", "status": 403}"#,
+        );
+        assert!(error.is_duplicate_content());
+        let error = ApiError::from_body(
+            StatusCode::FORBIDDEN,
+            None,
+            r#"{"detail": "You are not permitted to perform this action.", "type": "about:blank", "title": "Forbidden", "status": 403}"#,
+        );
+        assert!(error.is_forbidden());
+        assert!(!error.is_duplicate_content());
+    }
+
+    #[test]
+    fn lookup_with_missing_tweet() {
+        let payload: ApiPayload<Vec<Tweet>> = serde_json::from_str(
+            r#"{
+                "data": [{"id": "20", "text": "just setting up my twttr", "edit_history_tweet_ids": ["20"]}],
+                "errors": [{
+                    "value": "1276230436478386177",
+                    "detail": "Could not find tweet with ids: [1276230436478386177].",
+                    "title": "Not Found Error",
+                    "resource_type": "tweet",
+                    "parameter": "ids",
+                    "resource_id": "1276230436478386177",
+                    "type": "https://api.twitter.com/2/problems/resource-not-found"
+                }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(payload.data.unwrap().len(), 1);
+        let errors = payload.errors.unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].is_not_found());
+        assert_eq!(
+            errors[0].resource_id.as_deref(),
+            Some("1276230436478386177")
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "Not Found Error: Could not find tweet with ids: [1276230436478386177]."
+        );
+    }
+
+    #[test]
+    fn duplicate_stream_rule() {
+        let payload: ApiPayload<Vec<StreamRule>, StreamRuleMeta> = serde_json::from_str(
+            r#"{
+                "meta": {
+                    "sent": "2022-06-16T21:54:35.524Z",
+                    "summary": {"created": 0, "not_created": 1, "valid": 0, "invalid": 1}
+                },
+                "errors": [{
+                    "value": "cat has:images",
+                    "id": "1273026480692322304",
+                    "title": "DuplicateRule",
+                    "type": "https://api.twitter.com/2/problems/duplicate-rules"
+                }]
+            }"#,
+        )
+        .unwrap();
+        assert!(payload.data.is_none());
+        let summary = payload.meta.unwrap().summary.unwrap();
+        assert_eq!(summary.not_created, Some(1));
+        let errors = payload.errors.unwrap();
+        assert_eq!(errors[0].id, Some(StringId::from("1273026480692322304")));
+        assert_eq!(errors[0].detail, None);
+        assert!(!errors[0].is_not_found());
+        assert_eq!(errors[0].to_string(), "DuplicateRule");
+    }
 }
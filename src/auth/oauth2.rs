@@ -74,6 +74,9 @@ pub enum Scope {
     #[strum(serialize = "bookmark.write")]
     #[serde(rename = "bookmark.write")]
     BookmarkWrite,
+    #[strum(serialize = "media.write")]
+    #[serde(rename = "media.write")]
+    MediaWrite,
 }
 
 /// Oauth2Client is a wrapper around the oauth2::BasicClient for handling OAuth 2.0 authentication with Twitter.
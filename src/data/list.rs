@@ -0,0 +1,25 @@
+use crate::id::NumericId;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct List {
+    pub id: NumericId,
+    pub name: String,
+    #[serde(
+        default,
+        with = "time::serde::rfc3339::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at: Option<OffsetDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follower_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<NumericId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+}
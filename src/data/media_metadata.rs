@@ -0,0 +1,48 @@
+use crate::id::NumericId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct MediaAltText {
+    pub text: String,
+}
+
+impl MediaAltText {
+    /// Length limit of alt text, in characters.
+    pub const MAX_LEN: usize = 1000;
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct MediaMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_text: Option<MediaAltText>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct MediaMetadataStatus {
+    pub id: NumericId,
+    #[serde(default)]
+    pub associated_metadata: MediaMetadata,
+}
+
+/// Category of a video that subtitles are added to, as spelled by the subtitles endpoints.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum VideoCategory {
+    TweetVideo,
+    AmplifyVideo,
+}
+
+/// A subtitle track, itself uploaded as media of the `Subtitles` category.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct MediaSubtitle {
+    pub id: NumericId,
+    /// BCP 47 language code, e.g. `EN`.
+    pub language_code: String,
+    pub display_name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct MediaSubtitles {
+    pub id: NumericId,
+    #[serde(default)]
+    pub associated_subtitles: Vec<MediaSubtitle>,
+}
@@ -0,0 +1,87 @@
+use crate::id::NumericId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaCategory {
+    TweetImage,
+    TweetGif,
+    TweetVideo,
+    AmplifyVideo,
+    DmImage,
+    DmGif,
+    DmVideo,
+    Subtitles,
+}
+
+impl MediaCategory {
+    /// The category used for Tweet attachments of the given MIME type.
+    pub fn for_media_type(media_type: &str) -> Self {
+        if media_type == "image/gif" {
+            Self::TweetGif
+        } else if media_type.starts_with("video/") {
+            Self::TweetVideo
+        } else if media_type.starts_with("text/") {
+            Self::Subtitles
+        } else {
+            Self::TweetImage
+        }
+    }
+
+    /// Whether media of this category can be sent in a single request instead of chunks.
+    pub fn is_image(self) -> bool {
+        matches!(self, Self::TweetImage | Self::DmImage)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessingState {
+    Pending,
+    InProgress,
+    Failed,
+    Succeeded,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ProcessingError {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ProcessingInfo {
+    pub state: ProcessingState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_after_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_percent: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ProcessingError>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct MediaUpload {
+    pub id: NumericId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing_info: Option<ProcessingInfo>,
+}
+
+impl MediaUpload {
+    /// Whether the media can be attached to a Tweet, i.e. it needs no (more) processing.
+    pub fn is_ready(&self) -> bool {
+        self.processing_info
+            .as_ref()
+            .is_none_or(|info| info.state == ProcessingState::Succeeded)
+    }
+}
@@ -1,6 +1,14 @@
 mod entity;
 mod geo;
+mod list;
+mod media;
+mod media_metadata;
+mod media_upload;
+mod place;
+mod poll;
+mod status;
 mod tweet;
+mod tweet_count;
 mod user;
 mod withheld;
 
@@ -10,6 +18,21 @@ pub use entity::{
     UrlImage,
 };
 pub use geo::{GeoCoordinates, GeoCoordinatesKind, GeoFeature, GeoFeatureKind};
+pub use list::List;
+pub use media::{Media, MediaPublicMetrics, MediaType, MediaVariant};
+pub use media_metadata::{
+    MediaAltText, MediaMetadata, MediaMetadataStatus, MediaSubtitle, MediaSubtitles, VideoCategory,
+};
+pub use media_upload::{
+    MediaCategory, MediaUpload, ProcessingError, ProcessingInfo, ProcessingState,
+};
+pub use place::Place;
+pub use poll::{Poll, PollOption, PollVotingStatus};
+pub use status::{
+    BlockStatus, BookmarkStatus, DeleteStatus, FollowStatus, HideReplyStatus, LikeStatus,
+    ListMemberStatus, MuteStatus, PinStatus, RetweetStatus, UpdateStatus,
+};
 pub use tweet::{ReplySettings, Tweet, TweetPublicMetrics};
+pub use tweet_count::TweetCount;
 pub use user::{User, UserEntities, UserPublicMetrics, UserUrlEntities};
 pub use withheld::{Withheld, WithheldScope};
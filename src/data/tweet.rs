@@ -2,11 +2,20 @@ use super::geo::GeoCoordinates;
 use super::withheld::Withheld;
 use crate::id::StringId;
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct Tweet {
     pub id: StringId,
     pub text: String,
+    #[serde(
+        default,
+        with = "time::serde::rfc3339::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at: Option<OffsetDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edit_history_tweet_ids: Option<Vec<StringId>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author_id: Option<StringId>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -168,4 +177,8 @@ pub struct TweetPublicMetrics {
     pub like_count: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quote_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bookmark_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impression_count: Option<usize>,
 }
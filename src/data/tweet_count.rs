@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Number of Tweets matching a query within `[start, end)`.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TweetCount {
+    #[serde(with = "time::serde::rfc3339")]
+    pub start: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub end: OffsetDateTime,
+    pub tweet_count: usize,
+}
@@ -1,17 +1,42 @@
 use crate::api_result::ApiError;
+use crate::id::NumericId;
+use crate::query::QueryError;
 use reqwest::header::InvalidHeaderValue;
+use std::time::Duration;
 use thiserror::Error;
+use time::OffsetDateTime;
 
 #[derive(Debug, Error)]
 pub enum Error {
     #[error(transparent)]
-    Api(#[from] ApiError),
+    Api(Box<ApiError>),
     #[error(transparent)]
     Request(#[from] reqwest::Error),
     #[error(transparent)]
     Url(#[from] url::ParseError),
     #[error(transparent)]
     Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Query(#[from] QueryError),
+    #[error("Rate limit for {endpoint} exhausted until {reset}")]
+    RateLimited {
+        endpoint: String,
+        reset: OffsetDateTime,
+    },
+    #[error("Processing of media {media_id} failed: {message}")]
+    MediaProcessing {
+        media_id: NumericId,
+        message: String,
+    },
+    #[error("Media source does not match its declared size of {declared} bytes (read {read})")]
+    MediaSizeMismatch { declared: u64, read: u64 },
+    #[error("Media {media_id} was not processed within {timeout:?}")]
+    MediaProcessingTimeout {
+        media_id: NumericId,
+        timeout: Duration,
+    },
     #[error("Invalid Authorization header value: {_0}")]
     InvalidAuthorizationHeader(InvalidHeaderValue),
     #[cfg(feature = "oauth2")]
@@ -45,6 +70,12 @@ pub enum Error {
     Custom(String),
 }
 
+impl From<ApiError> for Error {
+    fn from(error: ApiError) -> Self {
+        Self::Api(Box::new(error))
+    }
+}
+
 impl Error {
     pub fn custom(message: impl ToString) -> Self {
         Self::Custom(message.to_string())
@@ -0,0 +1,87 @@
+use crate::api_result::ApiPayload;
+use crate::auth::Authorization;
+use crate::data::{Tweet, User};
+use crate::error::Result;
+use crate::requests::Paginator;
+use futures::prelude::*;
+use serde::Serialize;
+use std::io::Write;
+use time::format_description::well_known::Rfc3339;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One JSON object per line: the Tweet with its expanded author under `author`.
+    JsonLines,
+    /// One section per Tweet with the author, time, quoted text and a link to the Tweet.
+    Markdown,
+}
+
+#[derive(Serialize)]
+struct ExportedTweet<'a> {
+    #[serde(flatten)]
+    tweet: &'a Tweet,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<&'a User>,
+}
+
+/// Walks every page of `tweets` and writes each Tweet to `writer`, resolving its author from the
+/// page's includes. Request the `author_id` expansion to get authors. Returns the number of
+/// Tweets written.
+pub async fn export_tweets<A, W>(
+    tweets: Paginator<A, Tweet>,
+    format: ExportFormat,
+    mut writer: W,
+) -> Result<usize>
+where
+    A: Authorization,
+    W: Write,
+{
+    let mut count = 0;
+    let mut pages = Box::pin(tweets.pages());
+    while let Some(page) = pages.try_next().await? {
+        let ApiPayload { data, includes, .. } = page.payload;
+        let includes = includes.unwrap_or_default();
+        for tweet in data.unwrap_or_default() {
+            let author = includes.tweet_author(&tweet);
+            match format {
+                ExportFormat::JsonLines => {
+                    serde_json::to_writer(
+                        &mut writer,
+                        &ExportedTweet {
+                            tweet: &tweet,
+                            author,
+                        },
+                    )?;
+                    writeln!(writer)?;
+                }
+                ExportFormat::Markdown => write_markdown(&mut writer, &tweet, author)?,
+            }
+            count += 1;
+        }
+    }
+    writer.flush()?;
+    Ok(count)
+}
+
+fn write_markdown(writer: &mut impl Write, tweet: &Tweet, author: Option<&User>) -> Result<()> {
+    match author {
+        Some(author) => writeln!(writer, "## {} (@{})", author.name, author.username)?,
+        None => writeln!(writer, "## Tweet {}", tweet.id)?,
+    }
+    writeln!(writer)?;
+    for line in tweet.text.lines() {
+        writeln!(writer, "> {}", line)?;
+    }
+    writeln!(writer)?;
+    let username = author.map_or("i", |author| author.username.as_str());
+    let link = format!("https://twitter.com/{}/status/{}", username, tweet.id);
+    match tweet
+        .created_at
+        .and_then(|created_at| created_at.format(&Rfc3339).ok())
+    {
+        Some(created_at) => writeln!(writer, "[{}]({})", created_at, link)?,
+        None => writeln!(writer, "<{}>", link)?,
+    }
+    writeln!(writer)?;
+    Ok(())
+}
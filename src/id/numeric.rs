@@ -123,7 +123,7 @@ impl IntoNumericId for u64 {
     }
 }
 
-impl<'a> IntoNumericId for &'a u64 {
+impl IntoNumericId for &u64 {
     fn into_id(self) -> NumericId {
         NumericId(*self)
     }
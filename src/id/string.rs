@@ -107,7 +107,7 @@ impl IntoStringId for String {
     }
 }
 
-impl<'a> IntoStringId for &'a String {
+impl IntoStringId for &String {
     fn into_id(self) -> StringId {
         StringId(self.to_string())
     }
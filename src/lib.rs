@@ -6,16 +6,24 @@ pub mod api_result;
 pub mod auth;
 pub mod data;
 pub mod error;
+pub mod export;
 pub mod id;
+pub mod query;
+pub mod rate_limit;
 pub mod requests;
+pub mod retry;
 pub mod utils;
 
 pub use self::{
-    api::{TwitterApi, TwitterApiWithUserCtx},
-    api_result::{ApiError, ApiPayload, ApiResponse, ApiResult},
+    api::{TwitterApi, TwitterApiBuilder, TwitterApiWithUserCtx},
+    api_result::{
+        ApiError, ApiPayload, ApiPayloadError, ApiResponse, ApiResult, Includes, PaginationMeta,
+    },
     auth::Authorization,
     data::{TweetPublicMetrics, User, UserPublicMetrics, Withheld, WithheldScope},
     error::{Error, Result},
+    rate_limit::{RateLimit, RateLimitMode, RateLimitWindow},
+    retry::RetryPolicy,
 };
 
 pub mod prelude {
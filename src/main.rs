@@ -10,12 +10,12 @@ use std::io::{self, Write};
 use std::net::SocketAddr;
 use std::sync::{Arc, Mutex};
 use tower_http::trace::TraceLayer;
-use tracing;
 
 use oauth2::{AuthorizationCode, CsrfToken, PkceCodeChallenge, PkceCodeVerifier};
 use tracing_subscriber::prelude::*;
 use tweetterminal::auth::oauth2::{Oauth2Client, Oauth2Token, Scope};
-use tweetterminal::TwitterApi;
+use tweetterminal::query::{TweetExpansion, UserField};
+use tweetterminal::{ApiPayload, TwitterApi};
 
 pub struct Oauth2Ctx {
     client: Oauth2Client,
@@ -55,7 +55,7 @@ async fn login(Extension(ctx): Extension<Arc<Mutex<Oauth2Ctx>>>) -> impl IntoRes
     ctx.state = Some(state);
 
     // redirect to auth url
-    Redirect::to(&url.to_string())
+    Redirect::to(url.as_ref())
 }
 
 async fn callback(
@@ -272,10 +272,7 @@ async fn interactive_tweeting(ctx: Arc<Mutex<Oauth2Ctx>>) {
     }
 }
 
-async fn post_tweet_direct(
-    ctx: Arc<Mutex<Oauth2Ctx>>,
-    tweet_text: String,
-) -> Result<String, String> {
+async fn authenticated_api(ctx: Arc<Mutex<Oauth2Ctx>>) -> Result<TwitterApi<Oauth2Token>, String> {
     // Get OAuth token
     let (mut oauth_token, oauth_client) = {
         let ctx = ctx.lock().unwrap();
@@ -294,8 +291,15 @@ async fn post_tweet_direct(
         ctx.lock().unwrap().token = Some(oauth_token.clone());
     }
 
+    Ok(TwitterApi::new(oauth_token))
+}
+
+async fn post_tweet_direct(
+    ctx: Arc<Mutex<Oauth2Ctx>>,
+    tweet_text: String,
+) -> Result<String, String> {
     // Post tweet
-    let api = TwitterApi::new(oauth_token);
+    let api = authenticated_api(ctx).await?;
     let response = api
         .post_tweet()
         .text(tweet_text)
@@ -309,6 +313,62 @@ async fn post_tweet_direct(
     Ok(tweet_data.id.to_string())
 }
 
+async fn timeline_direct(
+    ctx: Arc<Mutex<Oauth2Ctx>>,
+    mentions: bool,
+) -> Result<Vec<String>, String> {
+    let api = authenticated_api(ctx)
+        .await?
+        .with_user_ctx()
+        .await
+        .map_err(|e| format!("User lookup failed: {}", e))?;
+    let response = if mentions {
+        api.get_my_mentions()
+            .expansions([TweetExpansion::AuthorId])
+            .max_results(10)
+            .send()
+            .await
+    } else {
+        api.get_home_timeline()
+            .expansions([TweetExpansion::AuthorId])
+            .max_results(10)
+            .send()
+            .await
+    }
+    .map_err(|e| format!("Timeline lookup failed: {}", e))?;
+
+    let ApiPayload { data, includes, .. } = response.payload;
+    let includes = includes.unwrap_or_default();
+    Ok(data
+        .unwrap_or_default()
+        .into_iter()
+        .map(|tweet| match includes.tweet_author(&tweet) {
+            Some(author) => format!("@{}: {}", author.username, tweet.text),
+            None => tweet.text,
+        })
+        .collect())
+}
+
+async fn whoami_direct(ctx: Arc<Mutex<Oauth2Ctx>>) -> Result<String, String> {
+    let api = authenticated_api(ctx).await?;
+    let response = api
+        .get_users_me()
+        .user_fields([UserField::PublicMetrics])
+        .send()
+        .await
+        .map_err(|e| format!("User lookup failed: {}", e))?;
+
+    let user = response.payload.data.ok_or("No user data in response")?;
+
+    Ok(match user.public_metrics {
+        Some(metrics) => format!(
+            "{} (@{}), {} followers",
+            user.name, user.username, metrics.followers_count
+        ),
+        None => format!("{} (@{})", user.name, user.username),
+    })
+}
+
 async fn interactive_tweeting_background(ctx: Arc<Mutex<Oauth2Ctx>>) {
     // Wait for OAuth login to complete
     loop {
@@ -326,6 +386,7 @@ async fn interactive_tweeting_background(ctx: Arc<Mutex<Oauth2Ctx>>) {
     }
 
     println!("\nlogin finish");
+    println!("type 'whoami' to show the logged in account, 'home' or 'mentions' to read");
 
     loop {
         print!("\nenter tweet: ");
@@ -347,6 +408,30 @@ async fn interactive_tweeting_background(ctx: Arc<Mutex<Oauth2Ctx>>) {
                     break;
                 }
 
+                if tweet_text.eq_ignore_ascii_case("whoami") {
+                    match whoami_direct(ctx.clone()).await {
+                        Ok(user) => println!("Logged in as {}", user),
+                        Err(e) => println!("Failed to look up account: {}", e),
+                    }
+                    continue;
+                }
+
+                if tweet_text.eq_ignore_ascii_case("home")
+                    || tweet_text.eq_ignore_ascii_case("mentions")
+                {
+                    let mentions = tweet_text.eq_ignore_ascii_case("mentions");
+                    match timeline_direct(ctx.clone(), mentions).await {
+                        Ok(tweets) if tweets.is_empty() => println!("Nothing to show"),
+                        Ok(tweets) => {
+                            for tweet in tweets {
+                                println!("\n{}", tweet);
+                            }
+                        }
+                        Err(e) => println!("Failed to load timeline: {}", e),
+                    }
+                    continue;
+                }
+
                 if tweet_text.len() > 280 {
                     println!("Tweet too long! ({} characters, max 280)", tweet_text.len());
                     continue;
@@ -0,0 +1,137 @@
+use strum::{Display, EnumString};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum TweetField {
+    Attachments,
+    AuthorId,
+    ContextAnnotations,
+    ConversationId,
+    CreatedAt,
+    EditControls,
+    EditHistoryTweetIds,
+    Entities,
+    Geo,
+    Id,
+    InReplyToUserId,
+    Lang,
+    NonPublicMetrics,
+    NoteTweet,
+    OrganicMetrics,
+    PossiblySensitive,
+    PromotedMetrics,
+    PublicMetrics,
+    ReferencedTweets,
+    ReplySettings,
+    Source,
+    Text,
+    Withheld,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum UserField {
+    CreatedAt,
+    Description,
+    Entities,
+    Id,
+    Location,
+    Name,
+    PinnedTweetId,
+    ProfileImageUrl,
+    Protected,
+    PublicMetrics,
+    Url,
+    Username,
+    Verified,
+    Withheld,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum MediaField {
+    AltText,
+    DurationMs,
+    Height,
+    MediaKey,
+    NonPublicMetrics,
+    OrganicMetrics,
+    PreviewImageUrl,
+    PromotedMetrics,
+    PublicMetrics,
+    #[strum(serialize = "type")]
+    Type,
+    Url,
+    Variants,
+    Width,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum PlaceField {
+    ContainedWithin,
+    Country,
+    CountryCode,
+    FullName,
+    Geo,
+    Id,
+    Name,
+    PlaceType,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum PollField {
+    DurationMinutes,
+    EndDatetime,
+    Id,
+    Options,
+    VotingStatus,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+pub enum TweetExpansion {
+    #[strum(serialize = "attachments.poll_ids")]
+    AttachmentsPollIds,
+    #[strum(serialize = "attachments.media_keys")]
+    AttachmentsMediaKeys,
+    #[strum(serialize = "author_id")]
+    AuthorId,
+    #[strum(serialize = "edit_history_tweet_ids")]
+    EditHistoryTweetIds,
+    #[strum(serialize = "entities.mentions.username")]
+    EntitiesMentionsUsername,
+    #[strum(serialize = "geo.place_id")]
+    GeoPlaceId,
+    #[strum(serialize = "in_reply_to_user_id")]
+    InReplyToUserId,
+    #[strum(serialize = "referenced_tweets.id")]
+    ReferencedTweetsId,
+    #[strum(serialize = "referenced_tweets.id.author_id")]
+    ReferencedTweetsIdAuthorId,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+pub enum UserExpansion {
+    #[strum(serialize = "pinned_tweet_id")]
+    PinnedTweetId,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum ListField {
+    CreatedAt,
+    Description,
+    FollowerCount,
+    Id,
+    MemberCount,
+    Name,
+    OwnerId,
+    Private,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+pub enum ListExpansion {
+    #[strum(serialize = "owner_id")]
+    OwnerId,
+}
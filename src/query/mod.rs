@@ -0,0 +1,55 @@
+mod fields;
+mod params;
+mod search;
+
+pub use fields::*;
+pub use params::*;
+pub use search::*;
+
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+use url::Url;
+
+pub(crate) trait UrlQueryExt {
+    /// Sets `key` to `value`, dropping any earlier value of the same key.
+    fn replace_query_val(&mut self, key: &str, value: impl ToString);
+    /// Sets `key` to the comma separated `values`, as used by the `*.fields` and `expansions`
+    /// parameters.
+    fn replace_query_seq(&mut self, key: &str, values: impl IntoIterator<Item = impl ToString>);
+    /// Sets `key` to `time` in the `YYYY-MM-DDTHH:mm:ssZ` format the API expects.
+    fn replace_query_time(&mut self, key: &str, time: OffsetDateTime);
+}
+
+impl UrlQueryExt for Url {
+    fn replace_query_val(&mut self, key: &str, value: impl ToString) {
+        let pairs = self
+            .query_pairs()
+            .filter(|(k, _)| k != key)
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect::<Vec<_>>();
+        self.query_pairs_mut()
+            .clear()
+            .extend_pairs(pairs)
+            .append_pair(key, &value.to_string());
+    }
+
+    fn replace_query_seq(&mut self, key: &str, values: impl IntoIterator<Item = impl ToString>) {
+        self.replace_query_val(
+            key,
+            values
+                .into_iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+
+    fn replace_query_time(&mut self, key: &str, time: OffsetDateTime) {
+        let time = time
+            .to_offset(UtcOffset::UTC)
+            .replace_nanosecond(0)
+            .unwrap_or(time);
+        // formatting a UTC timestamp as RFC 3339 cannot fail
+        self.replace_query_val(key, time.format(&Rfc3339).unwrap())
+    }
+}
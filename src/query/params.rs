@@ -0,0 +1,23 @@
+use strum::{Display, EnumString};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum SortOrder {
+    Recency,
+    Relevancy,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum Granularity {
+    Minute,
+    Hour,
+    Day,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum Exclude {
+    Replies,
+    Retweets,
+}
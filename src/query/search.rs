@@ -0,0 +1,443 @@
+use std::fmt;
+use std::ops::Not;
+use strum::{Display, EnumString};
+use thiserror::Error;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum IsOperator {
+    Retweet,
+    Reply,
+    Quote,
+    Verified,
+    Nullcast,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum HasOperator {
+    Hashtags,
+    Cashtags,
+    Links,
+    Mentions,
+    Media,
+    Images,
+    Videos,
+    Geo,
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    #[error("Query is {len} characters long, the limit is {max}")]
+    TooLong { len: usize, max: usize },
+    #[error("Query contains an empty group")]
+    EmptyGroup,
+    #[error("Invalid value for {operator}: {value:?}")]
+    InvalidValue {
+        operator: &'static str,
+        value: String,
+    },
+    #[error("Query needs at least one non-negated standalone operator")]
+    NoStandaloneOperator,
+}
+
+/// Typed search query that renders to the operator syntax shared by the search endpoints and
+/// filtered stream rules. Its `Display` output can be passed wherever a query string is taken.
+///
+/// ```
+/// # use tweetterminal::query::{IsOperator, SearchQuery};
+/// let query = SearchQuery::from("foo")
+///     .or(SearchQuery::hashtag("bar"))
+///     .and(!SearchQuery::is(IsOperator::Retweet));
+/// assert_eq!(query.to_string(), "(from:foo OR #bar) -is:retweet");
+/// ```
+///
+/// Parentheses are added wherever an `OR` is nested in an `AND` or vice versa. Use
+/// [`validate`](Self::validate) to catch queries the API would reject before sending them; the
+/// `*_query` variants of the search and counts endpoints and
+/// [`StreamRuleBuilder::add_query`](crate::requests::StreamRuleBuilder::add_query) do so with
+/// the limit of the respective endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SearchQuery {
+    Keyword(String),
+    Phrase(String),
+    From(String),
+    To(String),
+    Mention(String),
+    Hashtag(String),
+    Cashtag(String),
+    Is(IsOperator),
+    Has(HasOperator),
+    Lang(String),
+    And(Vec<SearchQuery>),
+    Or(Vec<SearchQuery>),
+    Not(Box<SearchQuery>),
+}
+
+impl SearchQuery {
+    /// Query length limit of recent search and filtered stream rules.
+    pub const MAX_LEN: usize = 512;
+    /// Query length limit of full-archive search.
+    pub const MAX_LEN_FULL_ARCHIVE: usize = 1024;
+
+    pub fn keyword(keyword: impl ToString) -> Self {
+        Self::Keyword(keyword.to_string())
+    }
+    pub fn phrase(phrase: impl ToString) -> Self {
+        Self::Phrase(phrase.to_string())
+    }
+    pub fn from(username: impl ToString) -> Self {
+        Self::From(strip_prefix(username, '@'))
+    }
+    pub fn to(username: impl ToString) -> Self {
+        Self::To(strip_prefix(username, '@'))
+    }
+    pub fn mention(username: impl ToString) -> Self {
+        Self::Mention(strip_prefix(username, '@'))
+    }
+    pub fn hashtag(tag: impl ToString) -> Self {
+        Self::Hashtag(strip_prefix(tag, '#'))
+    }
+    pub fn cashtag(tag: impl ToString) -> Self {
+        Self::Cashtag(strip_prefix(tag, '$'))
+    }
+    pub fn is(operator: IsOperator) -> Self {
+        Self::Is(operator)
+    }
+    pub fn has(operator: HasOperator) -> Self {
+        Self::Has(operator)
+    }
+    pub fn lang(lang: impl ToString) -> Self {
+        Self::Lang(lang.to_string())
+    }
+    pub fn all(queries: impl IntoIterator<Item = SearchQuery>) -> Self {
+        Self::And(queries.into_iter().collect())
+    }
+    pub fn any(queries: impl IntoIterator<Item = SearchQuery>) -> Self {
+        Self::Or(queries.into_iter().collect())
+    }
+
+    pub fn and(self, other: SearchQuery) -> Self {
+        match self {
+            Self::And(mut queries) => {
+                queries.push(other);
+                Self::And(queries)
+            }
+            query => Self::And(vec![query, other]),
+        }
+    }
+
+    pub fn or(self, other: SearchQuery) -> Self {
+        match self {
+            Self::Or(mut queries) => {
+                queries.push(other);
+                Self::Or(queries)
+            }
+            query => Self::Or(vec![query, other]),
+        }
+    }
+
+    /// Checks the rendered length against `max_len` and the operator rules the API enforces:
+    /// no empty groups, and at least one standalone operator (keywords, phrases, users, tags)
+    /// in every alternative, since `is:`, `has:`, `lang:` and negations cannot be used alone.
+    pub fn validate(&self, max_len: usize) -> Result<(), QueryError> {
+        self.validate_operators()?;
+        if !self.has_standalone() {
+            return Err(QueryError::NoStandaloneOperator);
+        }
+        let len = self.to_string().chars().count();
+        if len > max_len {
+            return Err(QueryError::TooLong { len, max: max_len });
+        }
+        Ok(())
+    }
+
+    fn validate_operators(&self) -> Result<(), QueryError> {
+        let check = |operator, value: &str, valid: fn(char) -> bool| {
+            if value.is_empty() || !value.chars().all(valid) {
+                Err(QueryError::InvalidValue {
+                    operator,
+                    value: value.to_string(),
+                })
+            } else {
+                Ok(())
+            }
+        };
+        let is_word = |c: char| !c.is_whitespace() && !"()\"".contains(c);
+        let is_name = |c: char| c.is_alphanumeric() || c == '_';
+        match self {
+            Self::Keyword(keyword) => check("keyword", keyword, is_word),
+            Self::Phrase(phrase) => check("phrase", phrase, |c| c != '"'),
+            Self::From(username) => check("from:", username, is_name),
+            Self::To(username) => check("to:", username, is_name),
+            Self::Mention(username) => check("@", username, is_name),
+            Self::Hashtag(tag) => check("#", tag, is_name),
+            Self::Cashtag(tag) => check("$", tag, is_name),
+            Self::Lang(lang) => check("lang:", lang, |c| c.is_ascii_alphabetic() || c == '-'),
+            Self::Is(_) | Self::Has(_) => Ok(()),
+            Self::And(queries) | Self::Or(queries) => {
+                if queries.is_empty() {
+                    return Err(QueryError::EmptyGroup);
+                }
+                queries.iter().try_for_each(Self::validate_operators)
+            }
+            Self::Not(query) => query.validate_operators(),
+        }
+    }
+
+    fn has_standalone(&self) -> bool {
+        match self {
+            Self::Keyword(_)
+            | Self::Phrase(_)
+            | Self::From(_)
+            | Self::To(_)
+            | Self::Mention(_)
+            | Self::Hashtag(_)
+            | Self::Cashtag(_) => true,
+            Self::Is(_) | Self::Has(_) | Self::Lang(_) | Self::Not(_) => false,
+            Self::And(queries) => queries.iter().any(Self::has_standalone),
+            Self::Or(queries) => queries.iter().all(Self::has_standalone),
+        }
+    }
+}
+
+fn strip_prefix(value: impl ToString, prefix: char) -> String {
+    let value = value.to_string();
+    value
+        .strip_prefix(prefix)
+        .map(str::to_string)
+        .unwrap_or(value)
+}
+
+impl Not for SearchQuery {
+    type Output = SearchQuery;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Self::Not(query) => *query,
+            query => Self::Not(Box::new(query)),
+        }
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Keyword(keyword) => f.write_str(keyword),
+            Self::Phrase(phrase) => write!(f, "\"{phrase}\""),
+            Self::From(username) => write!(f, "from:{username}"),
+            Self::To(username) => write!(f, "to:{username}"),
+            Self::Mention(username) => write!(f, "@{username}"),
+            Self::Hashtag(tag) => write!(f, "#{tag}"),
+            Self::Cashtag(tag) => write!(f, "${tag}"),
+            Self::Is(operator) => write!(f, "is:{operator}"),
+            Self::Has(operator) => write!(f, "has:{operator}"),
+            Self::Lang(lang) => write!(f, "lang:{lang}"),
+            Self::And(queries) => write_joined(
+                f,
+                queries,
+                " ",
+                |query| matches!(query, Self::Or(queries) if queries.len() > 1),
+            ),
+            Self::Or(queries) => write_joined(
+                f,
+                queries,
+                " OR ",
+                |query| matches!(query, Self::And(queries) if queries.len() > 1),
+            ),
+            Self::Not(query) => match query.as_ref() {
+                Self::And(queries) | Self::Or(queries) if queries.len() > 1 => {
+                    write!(f, "-({query})")
+                }
+                // `--foo` isn't valid syntax, a double negation is just the query itself
+                Self::Not(query) => write!(f, "{query}"),
+                query => write!(f, "-{query}"),
+            },
+        }
+    }
+}
+
+fn write_joined(
+    f: &mut fmt::Formatter<'_>,
+    queries: &[SearchQuery],
+    separator: &str,
+    needs_parens: fn(&SearchQuery) -> bool,
+) -> fmt::Result {
+    for (i, query) in queries.iter().enumerate() {
+        if i > 0 {
+            f.write_str(separator)?;
+        }
+        if needs_parens(query) {
+            write!(f, "({query})")?;
+        } else {
+            write!(f, "{query}")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn kw(keyword: &str) -> SearchQuery {
+        SearchQuery::keyword(keyword)
+    }
+
+    #[test]
+    fn renders_operators() {
+        let query = SearchQuery::all([
+            SearchQuery::phrase("hello world"),
+            SearchQuery::from("alice"),
+            SearchQuery::to("bob"),
+            SearchQuery::mention("carol"),
+            SearchQuery::hashtag("rust"),
+            SearchQuery::cashtag("TWTR"),
+            SearchQuery::is(IsOperator::Retweet),
+            SearchQuery::has(HasOperator::Media),
+            SearchQuery::lang("en"),
+        ]);
+        assert_eq!(
+            query.to_string(),
+            "\"hello world\" from:alice to:bob @carol #rust $TWTR is:retweet has:media lang:en"
+        );
+    }
+
+    #[test]
+    fn strips_prefixes() {
+        assert_eq!(SearchQuery::from("@alice"), SearchQuery::from("alice"));
+        assert_eq!(SearchQuery::to("@bob"), SearchQuery::to("bob"));
+        assert_eq!(
+            SearchQuery::mention("@carol"),
+            SearchQuery::mention("carol")
+        );
+        assert_eq!(SearchQuery::hashtag("#rust"), SearchQuery::hashtag("rust"));
+        assert_eq!(SearchQuery::cashtag("$TWTR"), SearchQuery::cashtag("TWTR"));
+        // only a single leading prefix is stripped
+        assert_eq!(SearchQuery::hashtag("##rust").to_string(), "##rust");
+    }
+
+    #[test]
+    fn parenthesizes_or_inside_and() {
+        let query = kw("a").and(kw("b").or(kw("c")));
+        assert_eq!(query.to_string(), "a (b OR c)");
+    }
+
+    #[test]
+    fn parenthesizes_and_inside_or() {
+        let query = kw("a").or(kw("b").and(kw("c")));
+        assert_eq!(query.to_string(), "a OR (b c)");
+    }
+
+    #[test]
+    fn flattens_chained_and_or() {
+        assert_eq!(kw("a").and(kw("b")).and(kw("c")).to_string(), "a b c");
+        assert_eq!(kw("a").or(kw("b")).or(kw("c")).to_string(), "a OR b OR c");
+    }
+
+    #[test]
+    fn single_element_groups_have_no_parens() {
+        let query = kw("a").and(SearchQuery::any([kw("b")]));
+        assert_eq!(query.to_string(), "a b");
+        let query = kw("a").or(SearchQuery::all([kw("b")]));
+        assert_eq!(query.to_string(), "a OR b");
+        assert_eq!((!SearchQuery::any([kw("b")])).to_string(), "-b");
+    }
+
+    #[test]
+    fn negates_operators_and_groups() {
+        assert_eq!((!kw("a")).to_string(), "-a");
+        let query = kw("skiing").and(!kw("snow").or(kw("day")));
+        assert_eq!(query.to_string(), "skiing -(snow OR day)");
+        let query = kw("skiing").and(!kw("snow").and(kw("day")));
+        assert_eq!(query.to_string(), "skiing -(snow day)");
+    }
+
+    #[test]
+    fn double_negation_is_identity() {
+        let query = kw("a").or(SearchQuery::hashtag("b"));
+        assert_eq!(!!query.clone(), query);
+        let nested = SearchQuery::Not(Box::new(SearchQuery::Not(Box::new(kw("a")))));
+        assert_eq!(nested.to_string(), "a");
+    }
+
+    #[test]
+    fn and_needs_one_standalone_operator() {
+        let query = kw("a").and(SearchQuery::is(IsOperator::Retweet));
+        assert_eq!(query.validate(SearchQuery::MAX_LEN), Ok(()));
+        let query = SearchQuery::is(IsOperator::Retweet).and(SearchQuery::lang("en"));
+        assert_eq!(
+            query.validate(SearchQuery::MAX_LEN),
+            Err(QueryError::NoStandaloneOperator)
+        );
+        let query = SearchQuery::has(HasOperator::Links).and(!kw("a"));
+        assert_eq!(
+            query.validate(SearchQuery::MAX_LEN),
+            Err(QueryError::NoStandaloneOperator)
+        );
+    }
+
+    #[test]
+    fn or_needs_standalone_operator_in_every_alternative() {
+        let query = kw("a").or(SearchQuery::from("b").and(SearchQuery::lang("en")));
+        assert_eq!(query.validate(SearchQuery::MAX_LEN), Ok(()));
+        let query = kw("a").or(SearchQuery::has(HasOperator::Media));
+        assert_eq!(
+            query.validate(SearchQuery::MAX_LEN),
+            Err(QueryError::NoStandaloneOperator)
+        );
+    }
+
+    #[test]
+    fn accepts_negated_groups() {
+        let query = kw("skiing").and(!kw("snow").or(kw("day")));
+        assert_eq!(query.validate(SearchQuery::MAX_LEN), Ok(()));
+        assert_eq!(
+            (!kw("snow").or(kw("day"))).validate(SearchQuery::MAX_LEN),
+            Err(QueryError::NoStandaloneOperator)
+        );
+    }
+
+    #[test]
+    fn rejects_empty_groups_and_invalid_values() {
+        assert_eq!(
+            kw("a")
+                .and(SearchQuery::any([]))
+                .validate(SearchQuery::MAX_LEN),
+            Err(QueryError::EmptyGroup)
+        );
+        assert_eq!(
+            SearchQuery::from("not a name").validate(SearchQuery::MAX_LEN),
+            Err(QueryError::InvalidValue {
+                operator: "from:",
+                value: "not a name".to_string(),
+            })
+        );
+        assert_eq!(
+            kw("a").and(!kw("b c")).validate(SearchQuery::MAX_LEN),
+            Err(QueryError::InvalidValue {
+                operator: "keyword",
+                value: "b c".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn enforces_length_limit() {
+        let query = kw(&"a".repeat(SearchQuery::MAX_LEN));
+        assert_eq!(query.validate(SearchQuery::MAX_LEN), Ok(()));
+        let query = kw(&"a".repeat(SearchQuery::MAX_LEN + 1));
+        assert_eq!(
+            query.validate(SearchQuery::MAX_LEN),
+            Err(QueryError::TooLong {
+                len: SearchQuery::MAX_LEN + 1,
+                max: SearchQuery::MAX_LEN,
+            })
+        );
+        assert_eq!(query.validate(SearchQuery::MAX_LEN_FULL_ARCHIVE), Ok(()));
+        // the limit counts characters, not bytes
+        let query = kw(&"é".repeat(SearchQuery::MAX_LEN));
+        assert_eq!(query.validate(SearchQuery::MAX_LEN), Ok(()));
+    }
+}
@@ -0,0 +1,294 @@
+use crate::error::{Error, Result};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, Url};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// Quota for one rate limit window as reported by the `x-*-limit`, `x-*-remaining` and
+/// `x-*-reset` response headers.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RateLimitWindow {
+    pub limit: u64,
+    pub remaining: u64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub reset: OffsetDateTime,
+}
+
+impl RateLimitWindow {
+    fn from_headers(headers: &HeaderMap, prefix: &str) -> Option<Self> {
+        let header = |name: &str| -> Option<i64> {
+            headers
+                .get(format!("{prefix}-{name}"))?
+                .to_str()
+                .ok()?
+                .trim()
+                .parse()
+                .ok()
+        };
+        Some(Self {
+            limit: header("limit")?.try_into().ok()?,
+            remaining: header("remaining")?.try_into().ok()?,
+            reset: OffsetDateTime::from_unix_timestamp(header("reset")?).ok()?,
+        })
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+/// Rate limit state attached to a response. `endpoint` is the per endpoint 15 minute window,
+/// while the 24 hour windows are only sent by endpoints with a daily post cap (e.g. creating
+/// Tweets).
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RateLimit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<RateLimitWindow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_24h: Option<RateLimitWindow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_24h: Option<RateLimitWindow>,
+}
+
+impl RateLimit {
+    /// Returns `None` if the response carried none of the rate limit headers.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let rate_limit = Self {
+            endpoint: RateLimitWindow::from_headers(headers, "x-rate-limit"),
+            app_24h: RateLimitWindow::from_headers(headers, "x-app-limit-24hour"),
+            user_24h: RateLimitWindow::from_headers(headers, "x-user-limit-24hour"),
+        };
+        if rate_limit == Self::default() {
+            None
+        } else {
+            Some(rate_limit)
+        }
+    }
+
+    pub fn windows(&self) -> impl Iterator<Item = &RateLimitWindow> {
+        [&self.endpoint, &self.app_24h, &self.user_24h]
+            .into_iter()
+            .flatten()
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.windows().any(RateLimitWindow::is_exhausted)
+    }
+
+    /// When the next request may be sent again, if any window is currently exhausted.
+    pub fn reset_at(&self) -> Option<OffsetDateTime> {
+        self.windows()
+            .filter(|window| window.is_exhausted())
+            .map(|window| window.reset)
+            .max()
+    }
+}
+
+/// What a rate limited [`TwitterApi`](crate::TwitterApi) does with a request whose quota is
+/// already used up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Sleep until the window resets, then send the request.
+    Wait,
+    /// Return [`Error::RateLimited`] without sending the request.
+    FailFast,
+}
+
+/// Remaining quota per endpoint, learned from the headers of previous responses. A limiter
+/// belongs to a single client (and is shared by its clones), so the buckets are also scoped to
+/// that client's credentials.
+#[derive(Clone, Debug)]
+pub(crate) struct RateLimiter {
+    mode: RateLimitMode,
+    buckets: Arc<Mutex<HashMap<String, RateLimit>>>,
+}
+
+impl RateLimiter {
+    pub fn new(mode: RateLimitMode) -> Self {
+        Self {
+            mode,
+            buckets: Default::default(),
+        }
+    }
+
+    /// Builds the bucket key for a request, e.g. `DELETE tweets/:id` for
+    /// `DELETE https://api.twitter.com/2/tweets/1234`.
+    pub fn endpoint(method: &Method, url: &Url) -> String {
+        let mut path = Vec::new();
+        let mut segments = url.path_segments().into_iter().flatten().peekable();
+        // skip the version prefix
+        if segments.peek() == Some(&"2") {
+            segments.next();
+        }
+        while let Some(segment) = segments.next() {
+            if segment.chars().all(|c| c.is_ascii_digit()) && !segment.is_empty() {
+                path.push(":id");
+            } else {
+                path.push(segment);
+                if segment == "username" && segments.next().is_some() {
+                    path.push(":username");
+                }
+            }
+        }
+        format!("{} {}", method, path.join("/"))
+    }
+
+    /// Reserves one request against the bucket of `endpoint`, waiting for the window to reset
+    /// or failing if the quota is used up.
+    pub async fn acquire(&self, endpoint: &str) -> Result<()> {
+        loop {
+            let reset = self.try_acquire(endpoint);
+            match (reset, self.mode) {
+                (None, _) => return Ok(()),
+                (Some(reset), RateLimitMode::FailFast) => {
+                    return Err(Error::RateLimited {
+                        endpoint: endpoint.to_string(),
+                        reset,
+                    })
+                }
+                (Some(reset), RateLimitMode::Wait) => {
+                    let wait: Duration = (reset - OffsetDateTime::now_utc())
+                        .try_into()
+                        .unwrap_or_default();
+                    tracing::debug!("Rate limit for {} exhausted, waiting {:?}", endpoint, wait);
+                    // the reset header only has second precision
+                    tokio::time::sleep(wait + Duration::from_secs(1)).await;
+                }
+            }
+        }
+    }
+
+    fn try_acquire(&self, endpoint: &str) -> Option<OffsetDateTime> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let rate_limit = buckets.get_mut(endpoint)?;
+        let now = OffsetDateTime::now_utc();
+        for window in [
+            &mut rate_limit.endpoint,
+            &mut rate_limit.app_24h,
+            &mut rate_limit.user_24h,
+        ] {
+            if window.is_some_and(|window| window.reset <= now) {
+                *window = None;
+            }
+        }
+        if let Some(reset) = rate_limit.reset_at() {
+            return Some(reset);
+        }
+        for window in [
+            &mut rate_limit.endpoint,
+            &mut rate_limit.app_24h,
+            &mut rate_limit.user_24h,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            window.remaining -= 1;
+        }
+        None
+    }
+
+    pub fn update(&self, endpoint: &str, rate_limit: Option<RateLimit>) {
+        if let Some(rate_limit) = rate_limit {
+            self.buckets
+                .lock()
+                .unwrap()
+                .insert(endpoint.to_string(), rate_limit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.parse().unwrap(), value.parse().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn rate_limit_from_headers() {
+        let rate_limit = RateLimit::from_headers(&headers(&[
+            ("x-rate-limit-limit", "900"),
+            ("x-rate-limit-remaining", "899"),
+            ("x-rate-limit-reset", "1655416800"),
+            ("x-user-limit-24hour-limit", "200"),
+            ("x-user-limit-24hour-remaining", "0"),
+            ("x-user-limit-24hour-reset", "1655481600"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            rate_limit.endpoint,
+            Some(RateLimitWindow {
+                limit: 900,
+                remaining: 899,
+                reset: OffsetDateTime::from_unix_timestamp(1655416800).unwrap(),
+            })
+        );
+        assert_eq!(rate_limit.app_24h, None);
+        assert!(rate_limit.is_exhausted());
+        assert_eq!(
+            rate_limit.reset_at(),
+            Some(OffsetDateTime::from_unix_timestamp(1655481600).unwrap())
+        );
+    }
+
+    #[test]
+    fn rate_limit_from_incomplete_headers() {
+        assert_eq!(RateLimit::from_headers(&HeaderMap::new()), None);
+        assert_eq!(
+            RateLimit::from_headers(&headers(&[
+                ("x-rate-limit-limit", "900"),
+                ("x-rate-limit-remaining", "-1"),
+                ("x-rate-limit-reset", "1655416800"),
+            ])),
+            None
+        );
+    }
+
+    fn endpoint(method: Method, url: &str) -> String {
+        RateLimiter::endpoint(&method, &url.parse().unwrap())
+    }
+
+    #[test]
+    fn endpoint_buckets() {
+        assert_eq!(
+            endpoint(Method::DELETE, "https://api.twitter.com/2/tweets/1234"),
+            "DELETE tweets/:id"
+        );
+        assert_eq!(
+            endpoint(
+                Method::GET,
+                "https://api.twitter.com/2/users/2244994945/liked_tweets?max_results=5"
+            ),
+            "GET users/:id/liked_tweets"
+        );
+        assert_eq!(
+            endpoint(
+                Method::GET,
+                "https://api.twitter.com/2/users/by/username/TwitterDev"
+            ),
+            "GET users/by/username/:username"
+        );
+        assert_eq!(
+            endpoint(
+                Method::POST,
+                "https://api.twitter.com/2/tweets/search/stream/rules"
+            ),
+            "POST tweets/search/stream/rules"
+        );
+        assert_eq!(
+            endpoint(
+                Method::POST,
+                "https://api.twitter.com/2/media/upload/1234/append"
+            ),
+            "POST media/upload/:id/append"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::api::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use url::Url;
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
+struct DraftList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+}
+
+/// Creates a List (`POST`) or changes an existing one (`PUT`). Unset fields are left as they
+/// are when updating.
+#[derive(Debug)]
+pub struct ListBuilder<A, T> {
+    client: TwitterApi<A>,
+    method: Method,
+    url: Url,
+    list: DraftList,
+    return_ty: PhantomData<fn() -> T>,
+}
+
+impl<A, T> ListBuilder<A, T>
+where
+    A: Authorization,
+    T: DeserializeOwned,
+{
+    pub(crate) fn new(client: &TwitterApi<A>, method: Method, url: Url) -> Self {
+        Self {
+            client: client.clone(),
+            method,
+            url,
+            list: Default::default(),
+            return_ty: PhantomData,
+        }
+    }
+
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.list.name = Some(name.to_string());
+        self
+    }
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.list.description = Some(description.to_string());
+        self
+    }
+    pub fn private(mut self, private: bool) -> Self {
+        self.list.private = Some(private);
+        self
+    }
+
+    pub async fn send(self) -> ApiResult<T> {
+        let req = self.client.request(self.method, self.url).json(&self.list);
+        self.client.send(req).await
+    }
+}
+
+impl<A, T> Clone for ListBuilder<A, T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            method: self.method.clone(),
+            url: self.url.clone(),
+            list: self.list.clone(),
+            return_ty: PhantomData,
+        }
+    }
+}
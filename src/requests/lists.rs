@@ -0,0 +1,81 @@
+use super::Paginator;
+use crate::api::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::List;
+use crate::query::{ListExpansion, ListField, UrlQueryExt, UserField};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use url::Url;
+
+/// Request for one or many Lists. `T` is either a single [`List`] or a `Vec` of them, depending
+/// on the endpoint.
+#[derive(Debug)]
+pub struct ListsRequestBuilder<A, T> {
+    client: TwitterApi<A>,
+    url: Url,
+    return_ty: PhantomData<fn() -> T>,
+}
+
+impl<A, T> ListsRequestBuilder<A, T>
+where
+    A: Authorization,
+    T: DeserializeOwned,
+{
+    pub(crate) fn new(client: &TwitterApi<A>, url: Url) -> Self {
+        Self {
+            client: client.clone(),
+            url,
+            return_ty: PhantomData,
+        }
+    }
+
+    pub fn list_fields(mut self, fields: impl IntoIterator<Item = ListField>) -> Self {
+        self.url.replace_query_seq("list.fields", fields);
+        self
+    }
+    /// Fields of the owner, if expanded.
+    pub fn user_fields(mut self, fields: impl IntoIterator<Item = UserField>) -> Self {
+        self.url.replace_query_seq("user.fields", fields);
+        self
+    }
+    pub fn expansions(mut self, expansions: impl IntoIterator<Item = ListExpansion>) -> Self {
+        self.url.replace_query_seq("expansions", expansions);
+        self
+    }
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.url.replace_query_val("max_results", max_results);
+        self
+    }
+    pub fn pagination_token(mut self, pagination_token: impl ToString) -> Self {
+        self.url
+            .replace_query_val("pagination_token", pagination_token);
+        self
+    }
+
+    pub async fn send(self) -> ApiResult<T> {
+        self.client
+            .send(self.client.request(Method::GET, self.url))
+            .await
+    }
+}
+
+impl<A> ListsRequestBuilder<A, Vec<List>>
+where
+    A: Authorization,
+{
+    pub fn paginate(self) -> Paginator<A, List> {
+        Paginator::new(&self.client, self.url, "pagination_token")
+    }
+}
+
+impl<A, T> Clone for ListsRequestBuilder<A, T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            url: self.url.clone(),
+            return_ty: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use crate::api::{check_alt_text, TwitterApi};
+use crate::api_result::{ApiResponse, ApiResult};
+use crate::auth::Authorization;
+use crate::data::{MediaCategory, MediaUpload, ProcessingState};
+use crate::error::{Error, Result};
+use crate::id::{IntoNumericId, NumericId};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use reqwest::Method;
+use serde::Serialize;
+use std::fmt;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::time::Instant;
+
+/// Images up to this size are sent in a single request, everything else is uploaded in chunks.
+const SIMPLE_UPLOAD_MAX_BYTES: u64 = 5 * 1024 * 1024;
+const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+const DEFAULT_PROCESSING_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+type BoxedReader = Pin<Box<dyn AsyncRead + Send>>;
+
+enum MediaSource {
+    Bytes(Vec<u8>),
+    File(PathBuf),
+    Reader {
+        reader: BoxedReader,
+        total_bytes: u64,
+    },
+}
+
+impl fmt::Debug for MediaSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::File(path) => f.debug_tuple("File").field(path).finish(),
+            Self::Reader { total_bytes, .. } => f
+                .debug_struct("Reader")
+                .field("total_bytes", total_bytes)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SimpleUpload<'a> {
+    media: String,
+    media_type: &'a str,
+    media_category: MediaCategory,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    additional_owners: &'a [String],
+}
+
+#[derive(Serialize)]
+struct InitializeUpload<'a> {
+    media_type: &'a str,
+    total_bytes: u64,
+    media_category: MediaCategory,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    additional_owners: &'a [String],
+}
+
+#[derive(Serialize)]
+struct AppendUpload {
+    media: String,
+    segment_index: usize,
+}
+
+fn guess_media_type(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    Some(match extension.as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "tif" | "tiff" => "image/tiff",
+        "mp4" => "video/mp4",
+        "mov" => "video/quicktime",
+        "webm" => "video/webm",
+        "srt" => "text/srt",
+        "vtt" => "text/vtt",
+        _ => return None,
+    })
+}
+
+/// Uploads a media file and waits until it is processed, yielding an id for
+/// [`TweetBuilder::add_media`](crate::requests::TweetBuilder::add_media).
+///
+/// Images of up to 5 MB are sent in one request; GIFs, videos and larger files go through the
+/// chunked INIT/APPEND/FINALIZE flow, polling STATUS until processing is done.
+#[derive(Debug)]
+pub struct MediaUploadBuilder<A> {
+    client: TwitterApi<A>,
+    source: MediaSource,
+    media_type: Option<String>,
+    category: Option<MediaCategory>,
+    additional_owners: Vec<String>,
+    chunk_size: usize,
+    processing_timeout: Duration,
+    alt_text: Option<String>,
+}
+
+impl<A> MediaUploadBuilder<A>
+where
+    A: Authorization,
+{
+    fn new(client: &TwitterApi<A>, source: MediaSource, media_type: Option<String>) -> Self {
+        Self {
+            client: client.clone(),
+            source,
+            media_type,
+            category: None,
+            additional_owners: Vec::new(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            processing_timeout: DEFAULT_PROCESSING_TIMEOUT,
+            alt_text: None,
+        }
+    }
+
+    pub(crate) fn from_bytes(
+        client: &TwitterApi<A>,
+        bytes: Vec<u8>,
+        media_type: impl ToString,
+    ) -> Self {
+        Self::new(
+            client,
+            MediaSource::Bytes(bytes),
+            Some(media_type.to_string()),
+        )
+    }
+
+    pub(crate) fn from_file(client: &TwitterApi<A>, path: PathBuf) -> Self {
+        let media_type = guess_media_type(&path).map(str::to_string);
+        Self::new(client, MediaSource::File(path), media_type)
+    }
+
+    pub(crate) fn from_reader(
+        client: &TwitterApi<A>,
+        reader: impl AsyncRead + Send + 'static,
+        total_bytes: u64,
+        media_type: impl ToString,
+    ) -> Self {
+        let source = MediaSource::Reader {
+            reader: Box::pin(reader),
+            total_bytes,
+        };
+        Self::new(client, source, Some(media_type.to_string()))
+    }
+
+    /// The MIME type of the media. Guessed from the extension when uploading a file.
+    pub fn media_type(mut self, media_type: impl ToString) -> Self {
+        self.media_type = Some(media_type.to_string());
+        self
+    }
+    /// Defaults to the Tweet category matching the media type.
+    pub fn category(mut self, category: MediaCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+    /// Lets other users attach the uploaded media to their Tweets as well.
+    pub fn additional_owners(
+        mut self,
+        user_ids: impl IntoIterator<Item = impl IntoNumericId>,
+    ) -> Self {
+        self.additional_owners
+            .extend(user_ids.into_iter().map(|id| id.to_string()));
+        self
+    }
+    /// Size of the segments of a chunked upload, 1 MB by default.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// How long to wait for the media to be processed before giving up, 10 minutes by default.
+    pub fn processing_timeout(mut self, processing_timeout: Duration) -> Self {
+        self.processing_timeout = processing_timeout;
+        self
+    }
+
+    /// Alt text to set once the media is processed.
+    pub fn alt_text(mut self, alt_text: impl ToString) -> Self {
+        self.alt_text = Some(alt_text.to_string());
+        self
+    }
+
+    pub async fn send(self) -> ApiResult<MediaUpload> {
+        let Self {
+            client,
+            source,
+            media_type,
+            category,
+            additional_owners,
+            chunk_size,
+            processing_timeout,
+            alt_text,
+        } = self;
+        let media_type = media_type.as_deref().ok_or_else(|| {
+            Error::custom("Unknown media type, set it with `MediaUploadBuilder::media_type`")
+        })?;
+        let category = category.unwrap_or_else(|| MediaCategory::for_media_type(media_type));
+        // Catch overlong alt text before spending time on the upload
+        if let Some(alt_text) = alt_text.as_deref() {
+            check_alt_text(alt_text)?;
+        }
+        let (mut reader, total_bytes): (BoxedReader, u64) = match source {
+            MediaSource::Bytes(bytes) => {
+                let total_bytes = bytes.len() as u64;
+                (Box::pin(Cursor::new(bytes)), total_bytes)
+            }
+            MediaSource::File(path) => {
+                let file = tokio::fs::File::open(path).await?;
+                let total_bytes = file.metadata().await?.len();
+                (Box::pin(file), total_bytes)
+            }
+            MediaSource::Reader {
+                reader,
+                total_bytes,
+            } => (reader, total_bytes),
+        };
+
+        let upload = if category.is_image() && total_bytes <= SIMPLE_UPLOAD_MAX_BYTES {
+            let mut media = Vec::with_capacity(total_bytes as usize);
+            // One byte past the declared size is enough to tell that the source is too long
+            (&mut reader)
+                .take(total_bytes + 1)
+                .read_to_end(&mut media)
+                .await?;
+            check_size(total_bytes, media.len() as u64)?;
+            let body = SimpleUpload {
+                media: BASE64.encode(media),
+                media_type,
+                media_category: category,
+                additional_owners: &additional_owners,
+            };
+            let req = client
+                .request(Method::POST, client.url("media/upload")?)
+                .json(&body);
+            client.send(req).await?
+        } else {
+            let body = InitializeUpload {
+                media_type,
+                total_bytes,
+                media_category: category,
+                additional_owners: &additional_owners,
+            };
+            let req = client
+                .request(Method::POST, client.url("media/upload/initialize")?)
+                .json(&body);
+            let init: ApiResponse<MediaUpload> = client.send(req).await?;
+            let media_id = init
+                .data()
+                .map(|upload| upload.id)
+                .ok_or_else(|| Error::custom("Media upload was not initialized"))?;
+            append(&client, media_id, &mut reader, total_bytes, chunk_size).await?;
+            let req = client.request(
+                Method::POST,
+                client.url(format!("media/upload/{}/finalize", media_id))?,
+            );
+            client.send(req).await?
+        };
+        let upload = wait_for_processing(&client, upload, processing_timeout).await?;
+        if let (Some(alt_text), Some(media)) = (alt_text, upload.data()) {
+            client.set_media_alt_text(media.id, alt_text).await?;
+        }
+        Ok(upload)
+    }
+}
+
+async fn append<A: Authorization>(
+    client: &TwitterApi<A>,
+    media_id: NumericId,
+    reader: &mut BoxedReader,
+    total_bytes: u64,
+    chunk_size: usize,
+) -> Result<()> {
+    let url = client.url(format!("media/upload/{}/append", media_id))?;
+    let mut chunk = vec![0; chunk_size];
+    let mut sent = 0;
+    for segment_index in 0.. {
+        let mut len = 0;
+        while len < chunk.len() {
+            match reader.read(&mut chunk[len..]).await? {
+                0 => break,
+                read => len += read,
+            }
+        }
+        if len == 0 {
+            break;
+        }
+        // Don't upload anything beyond the size declared in INIT
+        if sent + len as u64 > total_bytes {
+            return Err(Error::MediaSizeMismatch {
+                declared: total_bytes,
+                read: sent + len as u64,
+            });
+        }
+        sent += len as u64;
+        let body = AppendUpload {
+            media: BASE64.encode(&chunk[..len]),
+            segment_index,
+        };
+        let req = client.request(Method::POST, url.clone()).json(&body);
+        // Resending a segment replaces it, so appends are safe to retry
+        let _: ApiResponse<serde_json::Value> = client.send_retrying(req, true).await?;
+    }
+    check_size(total_bytes, sent)
+}
+
+fn check_size(declared: u64, read: u64) -> Result<()> {
+    if declared == read {
+        Ok(())
+    } else {
+        Err(Error::MediaSizeMismatch { declared, read })
+    }
+}
+
+async fn wait_for_processing<A: Authorization>(
+    client: &TwitterApi<A>,
+    mut upload: ApiResponse<MediaUpload>,
+    timeout: Duration,
+) -> ApiResult<MediaUpload> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let (media_id, info) = match upload.data() {
+            Some(MediaUpload {
+                id,
+                processing_info: Some(info),
+                ..
+            }) => (*id, info),
+            _ => return Ok(upload),
+        };
+        match info.state {
+            ProcessingState::Succeeded => return Ok(upload),
+            ProcessingState::Failed => {
+                let message = info
+                    .error
+                    .as_ref()
+                    .and_then(|error| error.message.clone().or_else(|| error.name.clone()))
+                    .unwrap_or_else(|| "unknown error".to_string());
+                return Err(Error::MediaProcessing { media_id, message });
+            }
+            ProcessingState::Pending | ProcessingState::InProgress => {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(Error::MediaProcessingTimeout { media_id, timeout });
+                }
+                let delay = Duration::from_secs(info.check_after_secs.unwrap_or(1).max(1))
+                    .min(deadline - now);
+                tracing::debug!(
+                    "Media {} is {:?}, checking again in {:?}",
+                    media_id,
+                    info.state,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                let mut url = client.url("media/upload")?;
+                url.query_pairs_mut()
+                    .append_pair("command", "STATUS")
+                    .append_pair("media_id", &media_id.to_string());
+                upload = client.send(client.request(Method::GET, url)).await?;
+            }
+        }
+    }
+}
@@ -1,5 +1,19 @@
+mod list;
+mod lists;
+mod media_upload;
+mod paginator;
 mod stream_rule;
 mod tweet;
+mod tweet_counts;
+mod tweets;
+mod users;
 
+pub use list::*;
+pub use lists::*;
+pub use media_upload::*;
+pub use paginator::*;
 pub use stream_rule::*;
 pub use tweet::*;
+pub use tweet_counts::*;
+pub use tweets::*;
+pub use users::*;
@@ -0,0 +1,137 @@
+use crate::api::TwitterApi;
+use crate::api_result::{ApiResult, PaginableApiResponse};
+use crate::auth::Authorization;
+use crate::error::Result;
+use crate::query::UrlQueryExt;
+use futures::prelude::*;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use url::Url;
+
+/// Follows the `next_token` of a paginated endpoint, yielding either whole pages or the items of
+/// every page.
+#[derive(Debug)]
+pub struct Paginator<A, T> {
+    client: TwitterApi<A>,
+    url: Url,
+    token_param: &'static str,
+    token: Option<String>,
+    max_pages: Option<usize>,
+    max_items: Option<usize>,
+    item: PhantomData<fn() -> T>,
+}
+
+struct PaginatorState<A, T> {
+    paginator: Paginator<A, T>,
+    pages: usize,
+    items: usize,
+    done: bool,
+}
+
+impl<A, T> Paginator<A, T>
+where
+    A: Authorization,
+    T: DeserializeOwned,
+{
+    /// `token_param` is the query parameter the endpoint expects the token in, which is
+    /// `pagination_token` for most endpoints but `next_token` for search. A token already set on
+    /// the request builder is where the pagination starts.
+    pub(crate) fn new(client: &TwitterApi<A>, url: Url, token_param: &'static str) -> Self {
+        let token = url
+            .query_pairs()
+            .find(|(key, _)| key == token_param)
+            .map(|(_, token)| token.into_owned());
+        Self {
+            client: client.clone(),
+            url,
+            token_param,
+            token,
+            max_pages: None,
+            max_items: None,
+            item: PhantomData,
+        }
+    }
+
+    /// Starts at a token saved from an earlier run instead of the first page, same as setting the
+    /// token on the request builder before calling `paginate`.
+    pub fn resume_from(mut self, token: impl ToString) -> Self {
+        self.token = Some(token.to_string());
+        self
+    }
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+    /// Stops requesting pages once this many items were received. [`items`](Self::items) also
+    /// drops the surplus of the last page.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    async fn next_page(&self) -> ApiResult<Vec<T>> {
+        let mut url = self.url.clone();
+        if let Some(token) = self.token.as_ref() {
+            url.replace_query_val(self.token_param, token);
+        }
+        self.client
+            .send(self.client.request(Method::GET, url))
+            .await
+    }
+
+    pub fn pages(self) -> impl Stream<Item = ApiResult<Vec<T>>> {
+        let state = PaginatorState {
+            paginator: self,
+            pages: 0,
+            items: 0,
+            done: false,
+        };
+        stream::try_unfold(state, |mut state| async move {
+            let paginator = &state.paginator;
+            if state.done
+                || paginator.max_pages.is_some_and(|max| state.pages >= max)
+                || paginator.max_items.is_some_and(|max| state.items >= max)
+            {
+                return Ok(None);
+            }
+            let page = paginator.next_page().await?;
+            state.pages += 1;
+            state.items += page.data().map_or(0, Vec::len);
+            match page.next_token() {
+                Some(token) => state.paginator.token = Some(token.to_string()),
+                None => state.done = true,
+            }
+            Ok(Some((page, state)))
+        })
+    }
+
+    pub fn items(self) -> impl Stream<Item = Result<T>> {
+        let max_items = self.max_items.unwrap_or(usize::MAX);
+        self.pages()
+            .map_ok(|page| {
+                stream::iter(
+                    PaginableApiResponse::into_data(page)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(Ok),
+                )
+            })
+            .try_flatten()
+            .take(max_items)
+    }
+}
+
+impl<A, T> Clone for Paginator<A, T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            url: self.url.clone(),
+            token_param: self.token_param,
+            token: self.token.clone(),
+            max_pages: self.max_pages,
+            max_items: self.max_items,
+            item: PhantomData,
+        }
+    }
+}
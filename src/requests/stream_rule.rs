@@ -1,9 +1,12 @@
 use crate::api::TwitterApi;
 use crate::api_result::ApiResult;
 use crate::auth::Authorization;
+use crate::error::Result;
 use crate::id::{IntoNumericId, NumericId, StringId};
+use crate::query::SearchQuery;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 use url::Url;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,10 +16,30 @@ pub struct StreamRule {
     pub tag: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StreamRuleSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_created: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_deleted: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invalid: Option<usize>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreamRuleMeta {
-    pub sent: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub sent: OffsetDateTime,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub result_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<StreamRuleSummary>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,6 +98,12 @@ where
         self
     }
 
+    /// Adds `query` as a rule after checking it against the rule syntax and length limit.
+    pub fn add_query(&mut self, query: &SearchQuery) -> Result<&mut Self> {
+        query.validate(SearchQuery::MAX_LEN)?;
+        Ok(self.add(query))
+    }
+
     pub fn delete_id(&mut self, id: impl IntoNumericId) -> &mut Self {
         self.delete_ids([id]);
         self
@@ -92,7 +121,7 @@ where
         self
     }
 
-    pub async fn send(&self) -> ApiResult<Vec<StreamRule>> {
+    pub async fn send(&self) -> ApiResult<Vec<StreamRule>, StreamRuleMeta> {
         self.client
             .send(
                 self.client
@@ -57,6 +57,7 @@ pub struct TweetBuilder<A> {
     client: TwitterApi<A>,
     url: Url,
     tweet: DraftTweet,
+    allow_retry: bool,
 }
 
 impl<A> TweetBuilder<A>
@@ -68,6 +69,7 @@ where
             client: client.clone(),
             url,
             tweet: Default::default(),
+            allow_retry: false,
         }
     }
 
@@ -167,12 +169,19 @@ where
         self
     }
 
+    /// Lets the client's retry policy apply to this Tweet. If a response gets lost after the
+    /// Tweet was created, the retry is rejected as duplicate content.
+    pub fn allow_retry(mut self) -> Self {
+        self.allow_retry = true;
+        self
+    }
+
     pub async fn send(self) -> ApiResult<Tweet> {
         let req = self
             .client
             .request(Method::POST, self.url)
             .json(&self.tweet);
-        self.client.send(req).await
+        self.client.send_retrying(req, self.allow_retry).await
     }
 }
 
@@ -182,6 +191,7 @@ impl<A> Clone for TweetBuilder<A> {
             client: self.client.clone(),
             url: self.url.clone(),
             tweet: self.tweet.clone(),
+            allow_retry: self.allow_retry,
         }
     }
 }
@@ -0,0 +1,77 @@
+use crate::api::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::TweetCount;
+use crate::id::IntoNumericId;
+use crate::query::{Granularity, UrlQueryExt};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use url::Url;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TweetCountsMeta {
+    pub total_tweet_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct TweetCountsRequestBuilder<A> {
+    client: TwitterApi<A>,
+    url: Url,
+}
+
+impl<A> TweetCountsRequestBuilder<A>
+where
+    A: Authorization,
+{
+    pub(crate) fn new(client: &TwitterApi<A>, url: Url) -> Self {
+        Self {
+            client: client.clone(),
+            url,
+        }
+    }
+
+    /// Size of the buckets of the time series, `hour` if not set.
+    pub fn granularity(mut self, granularity: Granularity) -> Self {
+        self.url.replace_query_val("granularity", granularity);
+        self
+    }
+    pub fn start_time(mut self, start_time: OffsetDateTime) -> Self {
+        self.url.replace_query_time("start_time", start_time);
+        self
+    }
+    pub fn end_time(mut self, end_time: OffsetDateTime) -> Self {
+        self.url.replace_query_time("end_time", end_time);
+        self
+    }
+    pub fn since_id(mut self, since_id: impl IntoNumericId) -> Self {
+        self.url.replace_query_val("since_id", since_id.into_id());
+        self
+    }
+    pub fn until_id(mut self, until_id: impl IntoNumericId) -> Self {
+        self.url.replace_query_val("until_id", until_id.into_id());
+        self
+    }
+    /// Only full-archive counts are paginated (by 31 days of buckets).
+    pub fn next_token(mut self, next_token: impl ToString) -> Self {
+        self.url.replace_query_val("next_token", next_token);
+        self
+    }
+
+    pub async fn send(self) -> ApiResult<Vec<TweetCount>, TweetCountsMeta> {
+        self.client
+            .send(self.client.request(Method::GET, self.url))
+            .await
+    }
+}
+
+impl<A> Clone for TweetCountsRequestBuilder<A> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            url: self.url.clone(),
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use super::Paginator;
+use crate::api::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::Tweet;
+use crate::id::IntoNumericId;
+use crate::query::{
+    Exclude, MediaField, PlaceField, PollField, SortOrder, TweetExpansion, TweetField, UrlQueryExt,
+    UserField,
+};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use time::OffsetDateTime;
+use url::Url;
+
+/// Lookup of one or many Tweets by id. `T` is either a single [`Tweet`](crate::data::Tweet) or
+/// a `Vec` of them, depending on the endpoint.
+#[derive(Debug)]
+pub struct TweetsRequestBuilder<A, T> {
+    client: TwitterApi<A>,
+    url: Url,
+    return_ty: PhantomData<fn() -> T>,
+}
+
+impl<A, T> TweetsRequestBuilder<A, T>
+where
+    A: Authorization,
+    T: DeserializeOwned,
+{
+    pub(crate) fn new(client: &TwitterApi<A>, url: Url) -> Self {
+        Self {
+            client: client.clone(),
+            url,
+            return_ty: PhantomData,
+        }
+    }
+
+    pub fn tweet_fields(mut self, fields: impl IntoIterator<Item = TweetField>) -> Self {
+        self.url.replace_query_seq("tweet.fields", fields);
+        self
+    }
+    pub fn user_fields(mut self, fields: impl IntoIterator<Item = UserField>) -> Self {
+        self.url.replace_query_seq("user.fields", fields);
+        self
+    }
+    pub fn media_fields(mut self, fields: impl IntoIterator<Item = MediaField>) -> Self {
+        self.url.replace_query_seq("media.fields", fields);
+        self
+    }
+    pub fn place_fields(mut self, fields: impl IntoIterator<Item = PlaceField>) -> Self {
+        self.url.replace_query_seq("place.fields", fields);
+        self
+    }
+    pub fn poll_fields(mut self, fields: impl IntoIterator<Item = PollField>) -> Self {
+        self.url.replace_query_seq("poll.fields", fields);
+        self
+    }
+    pub fn expansions(mut self, expansions: impl IntoIterator<Item = TweetExpansion>) -> Self {
+        self.url.replace_query_seq("expansions", expansions);
+        self
+    }
+
+    pub async fn send(self) -> ApiResult<T> {
+        self.client
+            .send(self.client.request(Method::GET, self.url))
+            .await
+    }
+}
+
+impl<A, T> Clone for TweetsRequestBuilder<A, T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            url: self.url.clone(),
+            return_ty: PhantomData,
+        }
+    }
+}
+
+/// Search for Tweets matching a query, page by page.
+#[derive(Debug)]
+pub struct TweetsSearchRequestBuilder<A> {
+    client: TwitterApi<A>,
+    url: Url,
+}
+
+impl<A> TweetsSearchRequestBuilder<A>
+where
+    A: Authorization,
+{
+    pub(crate) fn new(client: &TwitterApi<A>, url: Url) -> Self {
+        Self {
+            client: client.clone(),
+            url,
+        }
+    }
+
+    pub fn tweet_fields(mut self, fields: impl IntoIterator<Item = TweetField>) -> Self {
+        self.url.replace_query_seq("tweet.fields", fields);
+        self
+    }
+    pub fn user_fields(mut self, fields: impl IntoIterator<Item = UserField>) -> Self {
+        self.url.replace_query_seq("user.fields", fields);
+        self
+    }
+    pub fn media_fields(mut self, fields: impl IntoIterator<Item = MediaField>) -> Self {
+        self.url.replace_query_seq("media.fields", fields);
+        self
+    }
+    pub fn place_fields(mut self, fields: impl IntoIterator<Item = PlaceField>) -> Self {
+        self.url.replace_query_seq("place.fields", fields);
+        self
+    }
+    pub fn poll_fields(mut self, fields: impl IntoIterator<Item = PollField>) -> Self {
+        self.url.replace_query_seq("poll.fields", fields);
+        self
+    }
+    pub fn expansions(mut self, expansions: impl IntoIterator<Item = TweetExpansion>) -> Self {
+        self.url.replace_query_seq("expansions", expansions);
+        self
+    }
+    pub fn start_time(mut self, start_time: OffsetDateTime) -> Self {
+        self.url.replace_query_time("start_time", start_time);
+        self
+    }
+    pub fn end_time(mut self, end_time: OffsetDateTime) -> Self {
+        self.url.replace_query_time("end_time", end_time);
+        self
+    }
+    pub fn since_id(mut self, since_id: impl IntoNumericId) -> Self {
+        self.url.replace_query_val("since_id", since_id.into_id());
+        self
+    }
+    pub fn until_id(mut self, until_id: impl IntoNumericId) -> Self {
+        self.url.replace_query_val("until_id", until_id.into_id());
+        self
+    }
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.url.replace_query_val("max_results", max_results);
+        self
+    }
+    pub fn sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.url.replace_query_val("sort_order", sort_order);
+        self
+    }
+    pub fn next_token(mut self, next_token: impl ToString) -> Self {
+        self.url.replace_query_val("next_token", next_token);
+        self
+    }
+
+    pub async fn send(self) -> ApiResult<Vec<Tweet>> {
+        self.client
+            .send(self.client.request(Method::GET, self.url))
+            .await
+    }
+
+    pub fn paginate(self) -> Paginator<A, Tweet> {
+        Paginator::new(&self.client, self.url, "next_token")
+    }
+}
+
+impl<A> Clone for TweetsSearchRequestBuilder<A> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            url: self.url.clone(),
+        }
+    }
+}
+
+/// Marks a [`TimelineRequestBuilder`] for a plain paginated listing of Tweets, like liked Tweets
+/// or bookmarks.
+#[derive(Copy, Clone, Debug)]
+pub struct TweetList;
+
+/// Marks a [`TimelineRequestBuilder`] for the mentions of a user, which can be narrowed down to
+/// a time or id range.
+#[derive(Copy, Clone, Debug)]
+pub struct MentionsTimeline;
+
+/// Marks a [`TimelineRequestBuilder`] for the Tweets of a user or their home timeline, which can
+/// additionally leave out replies and Retweets.
+#[derive(Copy, Clone, Debug)]
+pub struct UserTimeline;
+
+/// Timelines that can be narrowed down to a time or id range.
+pub trait RangedTimeline {}
+
+impl RangedTimeline for MentionsTimeline {}
+impl RangedTimeline for UserTimeline {}
+
+/// Page-wise listing of Tweets. `K` is one of [`TweetList`], [`MentionsTimeline`] or
+/// [`UserTimeline`] and determines which parameters the endpoint accepts.
+#[derive(Debug)]
+pub struct TimelineRequestBuilder<A, K> {
+    client: TwitterApi<A>,
+    url: Url,
+    kind: PhantomData<fn() -> K>,
+}
+
+impl<A, K> TimelineRequestBuilder<A, K>
+where
+    A: Authorization,
+{
+    pub(crate) fn new(client: &TwitterApi<A>, url: Url) -> Self {
+        Self {
+            client: client.clone(),
+            url,
+            kind: PhantomData,
+        }
+    }
+
+    pub fn tweet_fields(mut self, fields: impl IntoIterator<Item = TweetField>) -> Self {
+        self.url.replace_query_seq("tweet.fields", fields);
+        self
+    }
+    pub fn user_fields(mut self, fields: impl IntoIterator<Item = UserField>) -> Self {
+        self.url.replace_query_seq("user.fields", fields);
+        self
+    }
+    pub fn media_fields(mut self, fields: impl IntoIterator<Item = MediaField>) -> Self {
+        self.url.replace_query_seq("media.fields", fields);
+        self
+    }
+    pub fn place_fields(mut self, fields: impl IntoIterator<Item = PlaceField>) -> Self {
+        self.url.replace_query_seq("place.fields", fields);
+        self
+    }
+    pub fn poll_fields(mut self, fields: impl IntoIterator<Item = PollField>) -> Self {
+        self.url.replace_query_seq("poll.fields", fields);
+        self
+    }
+    pub fn expansions(mut self, expansions: impl IntoIterator<Item = TweetExpansion>) -> Self {
+        self.url.replace_query_seq("expansions", expansions);
+        self
+    }
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.url.replace_query_val("max_results", max_results);
+        self
+    }
+    pub fn pagination_token(mut self, pagination_token: impl ToString) -> Self {
+        self.url
+            .replace_query_val("pagination_token", pagination_token);
+        self
+    }
+
+    pub async fn send(self) -> ApiResult<Vec<Tweet>> {
+        self.client
+            .send(self.client.request(Method::GET, self.url))
+            .await
+    }
+
+    pub fn paginate(self) -> Paginator<A, Tweet> {
+        Paginator::new(&self.client, self.url, "pagination_token")
+    }
+}
+
+impl<A, K> TimelineRequestBuilder<A, K>
+where
+    A: Authorization,
+    K: RangedTimeline,
+{
+    pub fn start_time(mut self, start_time: OffsetDateTime) -> Self {
+        self.url.replace_query_time("start_time", start_time);
+        self
+    }
+    pub fn end_time(mut self, end_time: OffsetDateTime) -> Self {
+        self.url.replace_query_time("end_time", end_time);
+        self
+    }
+    pub fn since_id(mut self, since_id: impl IntoNumericId) -> Self {
+        self.url.replace_query_val("since_id", since_id.into_id());
+        self
+    }
+    pub fn until_id(mut self, until_id: impl IntoNumericId) -> Self {
+        self.url.replace_query_val("until_id", until_id.into_id());
+        self
+    }
+}
+
+impl<A> TimelineRequestBuilder<A, UserTimeline>
+where
+    A: Authorization,
+{
+    /// Leaves replies and/or Retweets out of the timeline.
+    pub fn exclude(mut self, exclude: impl IntoIterator<Item = Exclude>) -> Self {
+        self.url.replace_query_seq("exclude", exclude);
+        self
+    }
+}
+
+impl<A, K> Clone for TimelineRequestBuilder<A, K> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            url: self.url.clone(),
+            kind: PhantomData,
+        }
+    }
+}
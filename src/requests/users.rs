@@ -0,0 +1,81 @@
+use super::Paginator;
+use crate::api::TwitterApi;
+use crate::api_result::ApiResult;
+use crate::auth::Authorization;
+use crate::data::User;
+use crate::query::{TweetField, UrlQueryExt, UserExpansion, UserField};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use url::Url;
+
+/// Request for one or many Users. `T` is either a single [`User`] or a `Vec` of them, depending
+/// on the endpoint.
+#[derive(Debug)]
+pub struct UsersRequestBuilder<A, T> {
+    client: TwitterApi<A>,
+    url: Url,
+    return_ty: PhantomData<fn() -> T>,
+}
+
+impl<A, T> UsersRequestBuilder<A, T>
+where
+    A: Authorization,
+    T: DeserializeOwned,
+{
+    pub(crate) fn new(client: &TwitterApi<A>, url: Url) -> Self {
+        Self {
+            client: client.clone(),
+            url,
+            return_ty: PhantomData,
+        }
+    }
+
+    pub fn user_fields(mut self, fields: impl IntoIterator<Item = UserField>) -> Self {
+        self.url.replace_query_seq("user.fields", fields);
+        self
+    }
+    /// Fields of the pinned Tweet, if expanded.
+    pub fn tweet_fields(mut self, fields: impl IntoIterator<Item = TweetField>) -> Self {
+        self.url.replace_query_seq("tweet.fields", fields);
+        self
+    }
+    pub fn expansions(mut self, expansions: impl IntoIterator<Item = UserExpansion>) -> Self {
+        self.url.replace_query_seq("expansions", expansions);
+        self
+    }
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.url.replace_query_val("max_results", max_results);
+        self
+    }
+    pub fn pagination_token(mut self, pagination_token: impl ToString) -> Self {
+        self.url
+            .replace_query_val("pagination_token", pagination_token);
+        self
+    }
+
+    pub async fn send(self) -> ApiResult<T> {
+        self.client
+            .send(self.client.request(Method::GET, self.url))
+            .await
+    }
+}
+
+impl<A> UsersRequestBuilder<A, Vec<User>>
+where
+    A: Authorization,
+{
+    pub fn paginate(self) -> Paginator<A, User> {
+        Paginator::new(&self.client, self.url, "pagination_token")
+    }
+}
+
+impl<A, T> Clone for UsersRequestBuilder<A, T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            url: self.url.clone(),
+            return_ty: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::error::Error;
+use rand::Rng;
+use reqwest::{Method, StatusCode};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// Controls how often [`TwitterApi`](crate::TwitterApi) retries a request after a transient
+/// failure. Delays grow exponentially from `base_delay` up to `max_delay`.
+///
+/// Only idempotent requests (`GET`, `PUT`, `DELETE`, ...) are retried. Requests that create
+/// something, like [`TweetBuilder::send`](crate::requests::TweetBuilder::send), have to opt in
+/// explicitly since a retry after a lost response could post twice.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    statuses: Vec<StatusCode>,
+    connection_errors: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            connection_errors: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Sends every request exactly once.
+    pub fn none() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// Total number of attempts including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+    /// Randomizes each delay between half and the full backoff so that concurrent clients don't
+    /// retry in lockstep.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+    pub fn retry_statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.statuses = statuses.into_iter().collect();
+        self
+    }
+    /// Whether connection failures, resets and timeouts are retried.
+    pub fn retry_connection_errors(mut self, connection_errors: bool) -> Self {
+        self.connection_errors = connection_errors;
+        self
+    }
+
+    pub(crate) fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub(crate) fn is_idempotent(method: &Method) -> bool {
+        method != Method::POST && method != Method::PATCH
+    }
+
+    pub(crate) fn is_retryable(&self, error: &Error) -> bool {
+        match error {
+            Error::Api(error) => self.statuses.contains(&error.status),
+            Error::Request(error) => {
+                self.connection_errors
+                    && (error.is_connect() || error.is_timeout() || error.is_request())
+            }
+            _ => false,
+        }
+    }
+
+    /// Delay before attempt `attempt + 1`. A rate limited response waits for its reset instead,
+    /// or isn't retried at all (`None`) if the reset is further away than `max_delay`.
+    pub(crate) fn delay(&self, attempt: u32, error: &Error) -> Option<Duration> {
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        let backoff = if self.jitter && !backoff.is_zero() {
+            rand::thread_rng().gen_range(backoff / 2..=backoff)
+        } else {
+            backoff
+        };
+        let reset = match error {
+            Error::Api(error) => error
+                .rate_limit
+                .and_then(|rate_limit| rate_limit.reset_at())
+                .and_then(|reset| (reset - OffsetDateTime::now_utc()).try_into().ok()),
+            _ => None,
+        };
+        match reset {
+            Some(reset) if reset > self.max_delay => None,
+            Some(reset) => Some(backoff.max(reset)),
+            None => Some(backoff),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_result::ApiError;
+    use crate::rate_limit::{RateLimit, RateLimitWindow};
+    use pretty_assertions::assert_eq;
+
+    fn api_error(status: StatusCode, reset_in: Option<Duration>) -> Error {
+        let rate_limit = reset_in.map(|reset_in| RateLimit {
+            endpoint: Some(RateLimitWindow {
+                limit: 900,
+                remaining: 0,
+                reset: OffsetDateTime::now_utc() + reset_in,
+            }),
+            ..Default::default()
+        });
+        ApiError {
+            status,
+            kind: "about:blank".to_string(),
+            title: status.to_string(),
+            detail: status.to_string(),
+            rate_limit,
+        }
+        .into()
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        let policy = RetryPolicy::default();
+        assert!(policy.is_retryable(&api_error(StatusCode::SERVICE_UNAVAILABLE, None)));
+        assert!(!policy.is_retryable(&api_error(StatusCode::FORBIDDEN, None)));
+        assert!(!policy.is_retryable(&Error::custom("not an API error")));
+        let policy = policy.retry_statuses([StatusCode::FORBIDDEN]);
+        assert!(policy.is_retryable(&api_error(StatusCode::FORBIDDEN, None)));
+    }
+
+    #[test]
+    fn exponential_delay() {
+        let policy = RetryPolicy::default()
+            .jitter(false)
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(5));
+        let error = api_error(StatusCode::BAD_GATEWAY, None);
+        assert_eq!(policy.delay(1, &error), Some(Duration::from_secs(1)));
+        assert_eq!(policy.delay(2, &error), Some(Duration::from_secs(2)));
+        assert_eq!(policy.delay(3, &error), Some(Duration::from_secs(4)));
+        assert_eq!(policy.delay(4, &error), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn jittered_delay() {
+        let policy = RetryPolicy::default().base_delay(Duration::from_secs(2));
+        let delay = policy
+            .delay(1, &api_error(StatusCode::BAD_GATEWAY, None))
+            .unwrap();
+        assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(2));
+    }
+
+    #[test]
+    fn rate_limited_delay() {
+        let policy = RetryPolicy::default()
+            .jitter(false)
+            .max_delay(Duration::from_secs(30));
+        let error = api_error(StatusCode::TOO_MANY_REQUESTS, Some(Duration::from_secs(10)));
+        let delay = policy.delay(1, &error).unwrap();
+        assert!(delay > Duration::from_secs(8) && delay <= Duration::from_secs(10));
+        let error = api_error(StatusCode::TOO_MANY_REQUESTS, Some(Duration::from_secs(60)));
+        assert_eq!(policy.delay(1, &error), None);
+    }
+}